@@ -0,0 +1,143 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use std::fmt::Display;
+
+/// One move of the head - the unit vector of the direction and how many steps to take
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Motion {
+    pub dir: (isize, isize),
+    pub steps: u32,
+}
+
+/// Advent of code - Day 9
+///
+/// NOTE: Not in Zig because I couldn't get Zig hash set to return the correct count wither with @vector2 or custom packed u32 key
+///
+/// Part 1 - Knots in a rope. Keep the tail next to the head and count visited spaces
+/// Part 2 - The rope is now 10 in length
+///
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Motion>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(run_simulation(input, 2))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(run_simulation(input, 10))
+    }
+}
+
+/// Parse the instructions for how the head moves "R 5", "U 2", etc
+///
+pub fn parse(data: &str) -> Result<Vec<Motion>, ParseError> {
+    data.lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let line_num = i + 1;
+            let (dir, step) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(line_num, line, line, "Expected a direction and number of steps"))?;
+
+            let dir = match dir {
+                "U" => (0, 1),
+                "D" => (0, -1),
+                "R" => (1, 0),
+                "L" => (-1, 0),
+                _ => return Err(ParseError::at(line_num, line, dir, "Expected a direction U, D, R or L")),
+            };
+
+            Ok(Motion {
+                dir,
+                steps: parse_field(line_num, line, step)?,
+            })
+        })
+        .collect()
+}
+
+/// Knots start at the same location
+/// Move head to the location and have the below knots move to within one and mark any travelled positions as visited
+/// knot won't move if it is within 1 even diagnonally but if it does move won't ever rest diagonally
+///     - so if it is in a different row or col it's first move will be diagonally
+///
+/// Returns the number of positions the tail (last knot) visited. There must be at least 1 knot
+///
+pub fn run_simulation(motions: &[Motion], num_knots: usize) -> usize {
+    assert!(num_knots > 0, "A rope needs at least one knot");
+
+    let mut knot_positions: Vec<(isize, isize)> = Vec::with_capacity(num_knots);
+    knot_positions.resize(num_knots, (0, 0));
+
+    let mut visited_set: std::collections::HashSet<(isize, isize)> =
+        std::collections::HashSet::new();
+
+    for &Motion { dir: v, steps } in motions {
+        for _ in 0..steps {
+            //Move head to the next location
+            knot_positions[0].0 += v.0;
+            knot_positions[0].1 += v.1;
+
+            //Move all the knots below the head
+            for k in 1..num_knots {
+                //Check if we need to move the tail
+                let (x_dist, y_dist) = (
+                    knot_positions[k - 1].0 - knot_positions[k].0,
+                    knot_positions[k - 1].1 - knot_positions[k].1,
+                );
+                if x_dist.abs() <= 1 && y_dist.abs() <= 1 {
+                    continue;
+                }
+
+                //Move the knot
+                knot_positions[k].0 += x_dist.signum();
+                knot_positions[k].1 += y_dist.signum();
+            }
+
+            //Record any unique tail positions
+            visited_set.insert(knot_positions[num_knots - 1]);
+        }
+    }
+    visited_set.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    const EXAMPLE_LARGER: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn two_knots() {
+        assert_eq!(run_simulation(&parse(EXAMPLE).unwrap(), 2), 13);
+    }
+
+    #[test]
+    fn ten_knots() {
+        assert_eq!(run_simulation(&parse(EXAMPLE).unwrap(), 10), 1);
+        assert_eq!(run_simulation(&parse(EXAMPLE_LARGER).unwrap(), 10), 36);
+    }
+
+    #[test]
+    fn reports_bad_direction() {
+        let e = parse("R 4\nX 2").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "X"));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::day_main::<day_9_2022::Day9>();
+}
@@ -0,0 +1,43 @@
+use std::io::IsTerminal;
+
+/// Prints the map with the main loop drawn in box drawing characters and the other tiles marked as
+/// inside (I) or outside (O) the loop, to check the parity rule by eye
+///
+/// Usage: cargo run -p day_10 --example render -- <input path> [--plain] [--method <parity|shoelace|flood>]
+///
+/// When writing to a terminal S is highlighted and pipes that aren't part of the loop are dimmed, unless --plain
+/// is given. Then the tiles furthest along the loop from S are listed and the enclosed tiles are counted with the
+/// given method, or every method to cross check them
+///
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let plain = args.iter().any(|a| a == "--plain");
+
+    let mut methods = day_10::AreaMethod::ALL.to_vec();
+    if let Some(i) = args.iter().position(|a| a == "--method") {
+        let method = args.get(i + 1).and_then(|m| day_10::AreaMethod::from_arg(m));
+        methods = vec![method.expect("--method must be parity, shoelace or flood")];
+        args.drain(i..i + 2);
+    }
+    let path = args.iter().find(|a| !a.starts_with("--")).expect("Need an input path");
+
+    let data = std::fs::read_to_string(path).expect("Could not read input");
+    let maze = match day_10::parse(&data) {
+        Ok(m) => m,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    print!("{}", day_10::render(&maze, !plain && std::io::stdout().is_terminal()));
+    let farthest: Vec<String> = maze.main_loop.farthest().iter().map(|(x, y)| format!("({}, {})", x, y)).collect();
+    println!(
+        "Farthest from S: {} at {} steps",
+        farthest.join(" and "),
+        maze.main_loop.farthest_distance()
+    );
+    for method in methods {
+        println!("{:?}: {} enclosed", method, day_10::enclosed(&maze, method));
+    }
+}
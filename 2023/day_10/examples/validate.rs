@@ -0,0 +1,27 @@
+/// Lists everything in a maze that doesn't fit together - pipes pointing at nothing or off the grid,
+/// a missing or repeated S, S without exactly two pipes joining it and pipes cut off from S
+///
+/// Usage: cargo run -p day_10 --example validate -- <input path>
+///
+/// Exits with 1 if there were any issues
+///
+fn main() {
+    let path = std::env::args().nth(1).expect("Need an input path");
+    let data = std::fs::read_to_string(path).expect("Could not read input");
+
+    let issues = match day_10::validate(&data) {
+        Ok(i) => i,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    for issue in &issues {
+        println!("{}", issue);
+    }
+    println!("{} issues", issues.len());
+    if !issues.is_empty() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{find_loop, regions, Maze, Region};
+use aoc_common::{Grid, Pos};
+
+/// The different ways of counting the tiles enclosed by the loop. They should always agree
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AreaMethod {
+    /// Count the loop crossings to the left of each tile
+    Parity,
+    /// Area of the loop polygon then Pick's theorem for the whole tiles inside it
+    Shoelace,
+    /// Fill in from the edge on a grid twice the size, so there is a gap between pipes that don't join
+    FloodFill,
+}
+
+impl AreaMethod {
+    pub const ALL: [AreaMethod; 3] = [AreaMethod::Parity, AreaMethod::Shoelace, AreaMethod::FloodFill];
+
+    pub fn from_arg(arg: &str) -> Option<AreaMethod> {
+        match arg {
+            "parity" => Some(AreaMethod::Parity),
+            "shoelace" => Some(AreaMethod::Shoelace),
+            "flood" => Some(AreaMethod::FloodFill),
+            _ => None,
+        }
+    }
+}
+
+/// The number of tiles enclosed by the loop
+///
+pub fn enclosed(maze: &Maze, method: AreaMethod) -> usize {
+    match method {
+        AreaMethod::Parity => regions(maze).cells().iter().filter(|&&r| r == Region::Inside).count(),
+        AreaMethod::Shoelace => shoelace(&find_loop(maze)),
+        AreaMethod::FloodFill => flood_fill(maze, &find_loop(maze)),
+    }
+}
+
+/// The loop tiles are the vertices of a polygon (in order) so the shoelace formula gives its area.
+/// Pick's theorem A = i + b/2 - 1 then gives the number of whole tiles inside, as every loop tile is on the boundary
+///
+fn shoelace(path: &[Pos]) -> usize {
+    let twice_area: i64 = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(&(x1, y1), &(x2, y2))| x1 as i64 * y2 as i64 - x2 as i64 * y1 as i64)
+        .sum();
+
+    //i = A - b/2 + 1, all doubled to stay in integers
+    ((twice_area.abs() - path.len() as i64 + 2) / 2).max(0) as usize
+}
+
+/// Tile (x, y) is at (2x + 1, 2y + 1) on the big grid with the joins between loop tiles filled in between them.
+/// Everything the fill can't reach from the border is inside
+///
+fn flood_fill(maze: &Maze, path: &[Pos]) -> usize {
+    let tiles = &maze.tiles;
+    let mut walls = Grid::new(tiles.width() * 2 + 1, tiles.height() * 2 + 1, false);
+
+    for (&(x1, y1), &(x2, y2)) in path.iter().zip(path.iter().cycle().skip(1)) {
+        walls[(x1 * 2 + 1, y1 * 2 + 1)] = true;
+        walls[(x1 + x2 + 1, y1 + y2 + 1)] = true;
+    }
+
+    let mut outside = Grid::new(walls.width(), walls.height(), false);
+    let mut open_stack: Vec<Pos> = vec![(0, 0)];
+    outside[(0, 0)] = true;
+
+    while let Some(curr) = open_stack.pop() {
+        for (_, next) in walls.neighbours(curr) {
+            if !walls[next] && !outside[next] {
+                outside[next] = true;
+                open_stack.push(next);
+            }
+        }
+    }
+
+    tiles
+        .positions()
+        .filter(|&(x, y)| {
+            let big = (x * 2 + 1, y * 2 + 1);
+            !walls[big] && !outside[big]
+        })
+        .count()
+}
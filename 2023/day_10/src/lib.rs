@@ -0,0 +1,363 @@
+use aoc_common::{Dir, Grid, ParseError, Pos, Solution};
+use std::fmt::Display;
+
+mod area;
+mod render;
+mod validate;
+mod walk;
+
+pub use area::{enclosed, AreaMethod};
+pub use render::render;
+pub use validate::{validate, Issue};
+pub use walk::{start_connections, walk_loop, Loop, LoopError, Step};
+
+/// The pipe tiles, the location of S and the loop through it
+///
+pub struct Maze {
+    pub tiles: Grid<u8>,
+    pub start: Pos,
+    pub main_loop: Loop,
+}
+
+/// Advent of code - Day 10
+///
+/// Part 1 - Given a sequence of pipes (horizontal, vertical, corners) navigate a looped path from S to S and find the furthest point
+/// Part 2 - Count the number of tiles enclosed in the loop
+///
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = Maze;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+/// Find the start node and the loop it's on
+///
+pub fn parse(data: &str) -> Result<Maze, ParseError> {
+    let tiles = Grid::parse_symbols(data, b"|-LJ7F.S")?;
+    let start = tiles
+        .find(|&t| t == b'S')
+        .ok_or_else(|| ParseError::at_end(data, "No start tile 'S'"))?;
+    let main_loop =
+        walk_loop(&tiles, start).map_err(|e| ParseError::new(start.1 + 1, start.0 + 1, "S", e.to_string()))?;
+    Ok(Maze { tiles, start, main_loop })
+}
+
+/// Walk the loop from S back to S - the furthest point is half way round
+///
+pub fn part1(maze: &Maze) -> usize {
+    maze.main_loop.farthest_distance()
+}
+
+/// Count the tiles enclosed by the loop
+///
+pub fn part2(maze: &Maze) -> usize {
+    enclosed(maze, AreaMethod::Parity)
+}
+
+/// Where a tile is relative to the main loop. Pipes that aren't part of the loop are inside or outside like the ground
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Region {
+    Loop,
+    Inside,
+    Outside,
+}
+
+/// Use knot theory such that looking at the elements to the left if there are an odd number then we are inside a loop
+///
+pub fn regions(maze: &Maze) -> Grid<Region> {
+    let tiles = resolved_tiles(maze);
+
+    let mut regions = Grid::new(tiles.width(), tiles.height(), Region::Outside);
+    for p in find_loop(maze) {
+        regions[p] = Region::Loop;
+    }
+
+    //Count how many |, J, L (the only types we could cross with a horizontal line) appear to the left of this index
+    //and if it is odd then this is in the loop. S has already been swapped for the pipe under it
+    for y in 0..tiles.height() {
+        let mut num_to_left: usize = 0;
+        for x in 0..tiles.width() {
+            match regions[(x, y)] {
+                Region::Loop if matches!(tiles[(x, y)], b'|' | b'J' | b'L') => num_to_left += 1,
+                Region::Loop => {}
+                _ if num_to_left % 2 == 1 => regions[(x, y)] = Region::Inside,
+                _ => {}
+            }
+        }
+    }
+
+    regions
+}
+
+/// The positions of the tiles in the loop, in order starting at S
+///
+pub fn find_loop(maze: &Maze) -> Vec<Pos> {
+    maze.main_loop.positions().collect()
+}
+
+/// The directions a pipe connects to, or None for ground and S
+///
+pub fn pipe_ends(tile: u8) -> Option<[Dir; 2]> {
+    match tile {
+        b'|' => Some([Dir::Up, Dir::Down]),
+        b'-' => Some([Dir::Left, Dir::Right]),
+        b'L' => Some([Dir::Up, Dir::Right]),
+        b'J' => Some([Dir::Up, Dir::Left]),
+        b'7' => Some([Dir::Down, Dir::Left]),
+        b'F' => Some([Dir::Down, Dir::Right]),
+        _ => None,
+    }
+}
+
+/// The pipe hidden under S - the one joining the two ends of the loop
+///
+pub fn start_pipe(maze: &Maze) -> u8 {
+    maze.main_loop.start_pipe()
+}
+
+/// The tiles with S replaced by the pipe under it
+///
+pub(crate) fn resolved_tiles(maze: &Maze) -> Grid<u8> {
+    let mut tiles = maze.tiles.clone();
+    tiles[maze.start] = start_pipe(maze);
+    tiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    const EXAMPLE_JUNK: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 8);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE_JUNK).unwrap()), 10);
+    }
+
+    const EXAMPLE_ENCLOSED: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    const EXAMPLE_LARGER: &str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 1);
+        assert_eq!(part2(&parse(EXAMPLE_ENCLOSED).unwrap()), 4);
+        assert_eq!(part2(&parse(EXAMPLE_LARGER).unwrap()), 8);
+    }
+
+    #[test]
+    fn area_methods_agree() {
+        let examples = [
+            (EXAMPLE, 1),
+            (EXAMPLE_ENCLOSED, 4),
+            (EXAMPLE_LARGER, 8),
+            (EXAMPLE_JUNK, 10),
+            (&snake_maze(40, 20), 0),
+        ];
+
+        for (data, expected) in examples {
+            let maze = parse(data).unwrap();
+            for method in AreaMethod::ALL {
+                assert_eq!(enclosed(&maze, method), expected, "{:?}", method);
+            }
+        }
+    }
+
+    #[test]
+    fn infers_start_pipe() {
+        assert_eq!(start_pipe(&parse(EXAMPLE).unwrap()), b'F');
+        assert_eq!(start_pipe(&parse(EXAMPLE_ENCLOSED).unwrap()), b'F');
+        assert_eq!(start_pipe(&parse(EXAMPLE_LARGER).unwrap()), b'F');
+        assert_eq!(start_pipe(&parse(EXAMPLE_JUNK).unwrap()), b'7');
+
+        //Three pipes connect to S but the one above is a dead end
+        assert_eq!(start_pipe(&parse("..|..\n..S-7\n..|.|\n..L-J").unwrap()), b'F');
+    }
+
+    #[test]
+    fn walks_loop_in_order() {
+        let maze = parse(EXAMPLE).unwrap();
+        let steps = &maze.main_loop.steps;
+        assert_eq!(steps[0].pos, maze.start);
+        for (step, next) in steps.iter().zip(steps.iter().cycle().skip(1)) {
+            assert_eq!(maze.tiles.step(step.pos, step.dir), Some(next.pos));
+        }
+        assert_eq!(maze.main_loop.farthest(), vec![(4, 2)]);
+        assert_eq!(maze.main_loop.farthest_distance(), 8);
+    }
+
+    #[test]
+    fn validates_examples() {
+        assert_eq!(validate(EXAMPLE).unwrap(), vec![]);
+        assert_eq!(validate(EXAMPLE_ENCLOSED).unwrap(), vec![]);
+        assert!(!validate(EXAMPLE_JUNK).unwrap().is_empty());
+        assert_eq!(validate("F7\nLJ").unwrap(), vec![Issue::NoStart]);
+    }
+
+    #[test]
+    fn reports_every_issue() {
+        let issues = validate("S-7.S\n|.|.-\nL-J.|\n..F-.").unwrap();
+        assert_eq!(
+            issues,
+            vec![
+                Issue::MultipleStarts { at: vec![(0, 0), (4, 0)] },
+                Issue::StartConnections { at: (4, 0), connections: 0 },
+                Issue::Unmatched { at: (4, 1), dir: Dir::Left },
+                Issue::OffGrid { at: (4, 1), dir: Dir::Right },
+                Issue::Unmatched { at: (4, 2), dir: Dir::Up },
+                Issue::Unmatched { at: (4, 2), dir: Dir::Down },
+                Issue::OffGrid { at: (2, 3), dir: Dir::Down },
+                Issue::Unmatched { at: (3, 3), dir: Dir::Right },
+                Issue::Disconnected { at: (4, 1), tiles: 1 },
+                Issue::Disconnected { at: (4, 2), tiles: 1 },
+                Issue::Disconnected { at: (2, 3), tiles: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn start_must_be_on_a_loop() {
+        let err = parse(".S.\n...").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 2));
+        assert!(err.message.contains("only 0 pipes"));
+
+        let err = parse("S-7\n|.|\nL-.").err().unwrap();
+        assert!(err.message.contains("leads nowhere"), "{}", err);
+    }
+
+    #[test]
+    fn renders_regions() {
+        let expected = "OOOOOOOOOOO
+O┌───────┐O
+O│┌─────┐│O
+O││OOOOO││O
+O││OOOOO││O
+O│└─┐O┌─┘│O
+O│II│O│II│O
+O└──┘O└──┘O
+OOOOOOOOOOO
+";
+        assert_eq!(render(&parse(EXAMPLE_ENCLOSED).unwrap(), false), expected);
+    }
+
+    #[test]
+    fn styles_start_and_junk_pipes() {
+        let maze = parse("S-7.\n|.|-\nL-J.").unwrap();
+        assert_eq!(render(&maze, false), "┌─┐O\n│I│O\n└─┘O\n");
+        assert_eq!(render(&maze, true), "\x1b[1m┌\x1b[0m─┐O\n│I│\x1b[2m─\x1b[0m\n└─┘O\n");
+
+        //A junk pipe inside the loop is dimmed as its shape when styled and marked I otherwise
+        let maze = parse("F-S-7\n|.F.|\nL---J").unwrap();
+        assert_eq!(render(&maze, false), "┌───┐\n│III│\n└───┘\n");
+        assert_eq!(render(&maze, true), "┌─\x1b[1m─\x1b[0m─┐\n│I\x1b[2m┌\x1b[0mI│\n└───┘\n");
+    }
+
+    #[test]
+    fn loop_starts_at_start() {
+        let maze = parse(EXAMPLE).unwrap();
+        let path = find_loop(&maze);
+        assert_eq!(path.len(), 16);
+        assert_eq!(path[0], maze.start);
+    }
+
+    /// A loop through every tile of the grid - along the top row, snaking back and forth over the
+    /// rest of the rows and then up the left hand column to S in the top left. The height must be even
+    ///
+    fn snake_maze(width: usize, height: usize) -> String {
+        let mut cycle: Vec<Pos> = (0..width).map(|x| (x, 0)).collect();
+        for y in 1..height {
+            let xs: Vec<usize> = (1..width).collect();
+            match y % 2 {
+                1 => cycle.extend(xs.iter().rev().map(|&x| (x, y))),
+                _ => cycle.extend(xs.iter().map(|&x| (x, y))),
+            }
+        }
+        cycle.extend((1..height).rev().map(|y| (0, y)));
+
+        let mut tiles = Grid::new(width, height, b'.');
+        for (i, &p) in cycle.iter().enumerate() {
+            let prev = cycle[(i + cycle.len() - 1) % cycle.len()];
+            let next = cycle[(i + 1) % cycle.len()];
+            let dirs = [prev, next].map(|q| match (q.0 as isize - p.0 as isize, q.1 as isize - p.1 as isize) {
+                (0, -1) => Dir::Up,
+                (0, 1) => Dir::Down,
+                (-1, 0) => Dir::Left,
+                _ => Dir::Right,
+            });
+
+            tiles[p] = match (dirs.contains(&Dir::Up), dirs.contains(&Dir::Down), dirs.contains(&Dir::Left)) {
+                _ if i == 0 => b'S',
+                (true, true, _) => b'|',
+                (true, false, true) => b'J',
+                (true, false, false) => b'L',
+                (false, true, true) => b'7',
+                (false, true, false) => b'F',
+                _ => b'-',
+            };
+        }
+
+        tiles.to_string()
+    }
+
+    #[test]
+    fn large_loop() {
+        let maze = parse(&snake_maze(400, 400)).unwrap();
+        assert_eq!(find_loop(&maze).len(), 160000);
+        assert_eq!(part1(&maze), 80000);
+    }
+
+    #[test]
+    fn requires_a_start() {
+        let e = parse("F7\nLJ").err().unwrap();
+        assert_eq!(e.line, 3);
+    }
+}
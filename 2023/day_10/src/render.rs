@@ -0,0 +1,59 @@
+use crate::{regions, start_pipe, Maze, Region};
+
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// The map with the main loop drawn in box drawing characters, enclosed tiles as I and the rest as O.
+/// S is drawn as the pipe under it
+///
+/// With `styled` S is highlighted and pipes that aren't part of the loop are drawn dimmed (with ANSI escape
+/// codes) so their shape can still be seen. Without it they are marked I or O like the ground, as they count
+/// towards part 2 the same way
+///
+pub fn render(maze: &Maze, styled: bool) -> String {
+    let regions = regions(maze);
+    let tiles = &maze.tiles;
+    let mut out = String::with_capacity(tiles.width() * tiles.height() * 3 + tiles.height());
+
+    for y in 0..tiles.height() {
+        for x in 0..tiles.width() {
+            let tile = match tiles[(x, y)] {
+                b'S' => start_pipe(maze),
+                t => t,
+            };
+
+            let (style, c) = match regions[(x, y)] {
+                Region::Loop if styled && (x, y) == maze.start => (Some(BOLD), box_char(tile)),
+                Region::Loop => (None, box_char(tile)),
+                _ if styled && tile != b'.' => (Some(DIM), box_char(tile)),
+                Region::Inside => (None, 'I'),
+                Region::Outside => (None, 'O'),
+            };
+
+            match style {
+                Some(style) => {
+                    out.push_str(style);
+                    out.push(c);
+                    out.push_str(RESET);
+                }
+                None => out.push(c),
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+fn box_char(tile: u8) -> char {
+    match tile {
+        b'|' => '│',
+        b'-' => '─',
+        b'L' => '└',
+        b'J' => '┘',
+        b'7' => '┐',
+        b'F' => '┌',
+        t => t as char,
+    }
+}
@@ -0,0 +1,140 @@
+use crate::pipe_ends;
+use aoc_common::{Dir, Grid, ParseError, Pos};
+use std::fmt;
+
+/// Something about a maze that doesn't fit together. Positions are (x, y) from 0 at the top left
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Issue {
+    NoStart,
+    MultipleStarts { at: Vec<Pos> },
+    /// S should have exactly two pipes connecting to it
+    StartConnections { at: Pos, connections: usize },
+    /// The end of a pipe points off the edge of the grid
+    OffGrid { at: Pos, dir: Dir },
+    /// The end of a pipe points at ground or at a pipe that doesn't connect back
+    Unmatched { at: Pos, dir: Dir },
+    /// Pipes joined to each other but not to S - the position is the first of them in reading order
+    Disconnected { at: Pos, tiles: usize },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Issue::NoStart => write!(f, "No start tile 'S'"),
+            Issue::MultipleStarts { at } => {
+                let at: Vec<String> = at.iter().map(|&p| place(p)).collect();
+                write!(f, "{} start tiles ({})", at.len(), at.join("; "))
+            }
+            Issue::StartConnections { at, connections } => {
+                write!(f, "{}: S has {} pipes connecting to it", place(*at), connections)
+            }
+            Issue::OffGrid { at, dir } => write!(f, "{}: pipe points {} off the grid", place(*at), name(*dir)),
+            Issue::Unmatched { at, dir } => {
+                write!(f, "{}: pipe points {} at a tile that doesn't connect back", place(*at), name(*dir))
+            }
+            Issue::Disconnected { at, tiles } => {
+                write!(f, "{}: {} pipes aren't connected to S", place(*at), tiles)
+            }
+        }
+    }
+}
+
+fn place((x, y): Pos) -> String {
+    format!("line {}, column {}", y + 1, x + 1)
+}
+
+fn name(dir: Dir) -> &'static str {
+    match dir {
+        Dir::Up => "up",
+        Dir::Down => "down",
+        Dir::Left => "left",
+        Dir::Right => "right",
+    }
+}
+
+/// Every inconsistency in the maze, without stopping at the first like parse does
+///
+/// Only an unknown symbol is an error. Puzzle inputs are full of junk pipes so expect plenty of
+/// unmatched and disconnected pipes in them - an empty list means every pipe is part of the loop
+///
+pub fn validate(data: &str) -> Result<Vec<Issue>, ParseError> {
+    let tiles = Grid::parse_symbols(data, b"|-LJ7F.S")?;
+    let mut issues = Vec::new();
+
+    let starts: Vec<Pos> = tiles.positions().filter(|&p| tiles[p] == b'S').collect();
+    match starts.len() {
+        0 => issues.push(Issue::NoStart),
+        1 => {}
+        _ => issues.push(Issue::MultipleStarts { at: starts.clone() }),
+    }
+
+    for &start in &starts {
+        let connections = connected(&tiles, start).count();
+        if connections != 2 {
+            issues.push(Issue::StartConnections { at: start, connections });
+        }
+    }
+
+    //S is taken to connect to anything pointing at it - how many do is checked above
+    for pos in tiles.positions() {
+        for dir in pipe_ends(tiles[pos]).into_iter().flatten() {
+            match tiles.step(pos, dir) {
+                None => issues.push(Issue::OffGrid { at: pos, dir }),
+                Some(next) if tiles[next] != b'S' && !points(&tiles, next, dir.opposite()) => {
+                    issues.push(Issue::Unmatched { at: pos, dir })
+                }
+                _ => {}
+            }
+        }
+    }
+
+    //Flood fill each group of joined pipes - the first start's group is the one that should have everything
+    if let Some(&start) = starts.first() {
+        let mut seen = Grid::new(tiles.width(), tiles.height(), false);
+        component(&tiles, start, &mut seen);
+
+        for pos in tiles.positions() {
+            if pipe_ends(tiles[pos]).is_some() && !seen[pos] {
+                let size = component(&tiles, pos, &mut seen);
+                issues.push(Issue::Disconnected { at: pos, tiles: size });
+            }
+        }
+    }
+
+    Ok(issues)
+}
+
+fn points(tiles: &Grid<u8>, pos: Pos, dir: Dir) -> bool {
+    pipe_ends(tiles[pos]).is_some_and(|ends| ends.contains(&dir))
+}
+
+/// The neighbours joined to this tile - S joins anything pointing at it
+///
+fn connected(tiles: &Grid<u8>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    tiles.neighbours(pos).filter_map(move |(dir, next)| {
+        let out = tiles[pos] == b'S' || points(tiles, pos, dir);
+        let back = tiles[next] == b'S' || points(tiles, next, dir.opposite());
+        (out && back).then_some(next)
+    })
+}
+
+/// Marks every tile joined to the given one as seen and returns how many there were
+///
+fn component(tiles: &Grid<u8>, from: Pos, seen: &mut Grid<bool>) -> usize {
+    let mut open_stack = vec![from];
+    let mut size = 0;
+    seen[from] = true;
+
+    while let Some(curr) = open_stack.pop() {
+        size += 1;
+        for next in connected(tiles, curr) {
+            if !seen[next] {
+                seen[next] = true;
+                open_stack.push(next);
+            }
+        }
+    }
+
+    size
+}
@@ -0,0 +1,151 @@
+use crate::pipe_ends;
+use aoc_common::{Dir, Grid, Pos};
+use std::fmt;
+
+/// A tile of the loop and the direction we leave it in
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub pos: Pos,
+    pub dir: Dir,
+}
+
+/// The main loop in the order it is walked, starting and ending at S
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Loop {
+    pub steps: Vec<Step>,
+}
+
+impl Loop {
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.steps.iter().map(|s| s.pos)
+    }
+
+    /// How far it is round the loop to the tile furthest from S, going whichever way is shorter
+    ///
+    pub fn farthest_distance(&self) -> usize {
+        self.steps.len() / 2
+    }
+
+    /// The tile furthest from S - or the two tiles either side of half way if the loop has an odd length,
+    /// which a loop on a square grid never does
+    ///
+    pub fn farthest(&self) -> Vec<Pos> {
+        let half = self.steps.len() / 2;
+        let mut tiles = vec![self.steps[half].pos];
+        if self.steps.len() % 2 == 1 {
+            tiles.push(self.steps[half + 1].pos);
+        }
+        tiles
+    }
+
+    /// The pipe under S joins the way we left it to the way we came back in
+    ///
+    pub fn start_pipe(&self) -> u8 {
+        let out = self.steps[0].dir;
+        let back = self.steps[self.steps.len() - 1].dir.opposite();
+        b"|-LJ7F"
+            .iter()
+            .copied()
+            .find(|&pipe| pipe_ends(pipe).is_some_and(|ends| ends.contains(&out) && ends.contains(&back)))
+            .expect("Loop leaves and enters S the same way")
+    }
+}
+
+/// Why there's no loop through S
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LoopError {
+    /// Fewer than two pipes connect to S
+    StartNotConnected { connections: usize },
+    /// Every way out of S comes to a dead end - the pipe at `at` leads to ground, another pipe's side or off the grid
+    NotClosed { at: Pos },
+}
+
+impl fmt::Display for LoopError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoopError::StartNotConnected { connections } => {
+                write!(f, "S is not on a closed loop - only {} pipes connect to it", connections)
+            }
+            LoopError::NotClosed { at: (x, y) } => {
+                write!(f, "S is not on a closed loop - the pipe at line {}, column {} leads nowhere", y + 1, x + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoopError {}
+
+/// The directions from S that have a pipe connecting back to it
+///
+pub fn start_connections(tiles: &Grid<u8>, start: Pos) -> Vec<Dir> {
+    Dir::ALL
+        .into_iter()
+        .filter(|&dir| {
+            tiles
+                .step(start, dir)
+                .and_then(|next| pipe_ends(tiles[next]))
+                .is_some_and(|ends| ends.contains(&dir.opposite()))
+        })
+        .collect()
+}
+
+/// Follow the pipes out of S until we get back to it
+///
+/// Each way out of S is tried in turn, so if more than two pipes connect to S the ones that are only
+/// junk are ruled out by coming to a dead end
+///
+pub fn walk_loop(tiles: &Grid<u8>, start: Pos) -> Result<Loop, LoopError> {
+    let connected = start_connections(tiles, start);
+    if connected.len() < 2 {
+        return Err(LoopError::StartNotConnected {
+            connections: connected.len(),
+        });
+    }
+
+    let mut first_dead_end = None;
+    for dir in connected {
+        match walk_from(tiles, start, dir) {
+            Ok(steps) => return Ok(Loop { steps }),
+            Err(at) => {
+                first_dead_end.get_or_insert(at);
+            }
+        }
+    }
+
+    Err(LoopError::NotClosed {
+        at: first_dead_end.expect("At least two ways out of S were tried"),
+    })
+}
+
+/// Every pipe has one way in and one way out, so the walk can only end back at S or at a dead end
+/// (which is returned as the error)
+///
+fn walk_from(tiles: &Grid<u8>, start: Pos, first_dir: Dir) -> Result<Vec<Step>, Pos> {
+    let mut steps = Vec::new();
+    let (mut pos, mut dir) = (start, first_dir);
+
+    loop {
+        steps.push(Step { pos, dir });
+        let next = tiles.step(pos, dir).ok_or(pos)?;
+        if next == start {
+            return Ok(steps);
+        }
+
+        //The next pipe has to connect back the way we came in, then we leave by its other end
+        let came_from = dir.opposite();
+        let ends = pipe_ends(tiles[next]).filter(|ends| ends.contains(&came_from)).ok_or(pos)?;
+        dir = if ends[0] == came_from { ends[1] } else { ends[0] };
+        pos = next;
+    }
+}
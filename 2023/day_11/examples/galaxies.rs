@@ -0,0 +1,67 @@
+/// Questions about the expanded universe rather than just the total distance
+///
+/// Usage: cargo run -p day_11 --example galaxies -- <input path> [--factor <n>] [--metric <name>] <query>
+///
+/// Queries (galaxies are numbered from 1 in reading order):
+///   distance <a> <b>    shortest distance between two galaxies
+///   nearest <g> <k>     the k galaxies closest to galaxy g
+///   farthest            the pair of galaxies furthest apart
+///   csv                 the distance between every pair as CSV - only for small maps
+///
+/// Each empty row and column has factor more added next to it (1 by default, as in part 1). The metric is
+/// manhattan (the default, as in the puzzle), chebyshev or euclidean2 for the squared euclidean distance
+///
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut factor = 1;
+    if let Some(i) = args.iter().position(|a| a == "--factor") {
+        factor = args.get(i + 1).and_then(|n| n.parse().ok()).unwrap_or_else(|| fail("--factor needs a number"));
+        args.drain(i..i + 2);
+    }
+
+    let mut metric = day_11::Metric::Manhattan;
+    if let Some(i) = args.iter().position(|a| a == "--metric") {
+        metric = args
+            .get(i + 1)
+            .and_then(|m| day_11::Metric::from_arg(m))
+            .unwrap_or_else(|| fail("--metric must be manhattan, chebyshev or euclidean2"));
+        args.drain(i..i + 2);
+    }
+
+    let path = args.first().unwrap_or_else(|| fail("Need an input path"));
+    let data = std::fs::read_to_string(path).unwrap_or_else(|e| fail(format!("Could not read {}: {}", path, e)));
+    let image = day_11::parse(&data).unwrap_or_else(|e| fail(e));
+    let universe = day_11::Universe::new(&image, factor, metric)
+        .unwrap_or_else(|| fail(format!("The galaxies are too far apart to measure with a factor of {}", factor)));
+
+    let number = |i: usize| -> usize {
+        args.get(i).and_then(|n| n.parse().ok()).unwrap_or_else(|| fail("Galaxy numbers and counts must be numbers"))
+    };
+    let no_galaxy = || -> ! { fail(format!("There are only {} galaxies", universe.len())) };
+
+    match args.get(1).map(|q| q.as_str()) {
+        Some("distance") => {
+            let dist = universe.distance(number(2), number(3)).unwrap_or_else(|| no_galaxy());
+            println!("{}", dist);
+        }
+        Some("nearest") => {
+            for (n, dist) in universe.nearest(number(2), number(3)).unwrap_or_else(|| no_galaxy()) {
+                println!("{} at {}", n, dist);
+            }
+        }
+        Some("farthest") => match universe.farthest_pair() {
+            Some((a, b, dist)) => println!("{} and {} at {}", a, b, dist),
+            None => no_galaxy(),
+        },
+        Some("csv") => print!("{}", universe.distance_csv()),
+        _ => fail("Query must be distance <a> <b>, nearest <g> <k>, farthest or csv"),
+    }
+}
+
+/// Bad arguments or input are reported and exit with 1 rather than panicking
+///
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(1);
+}
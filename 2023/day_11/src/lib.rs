@@ -0,0 +1,399 @@
+use aoc_common::{Grid, ParseError, Solution};
+use std::fmt::Display;
+
+mod space;
+mod universe;
+
+use space::empty_before;
+pub use space::{expand_points, parse_voxels, sum_distances_in, Metric, Point};
+pub use universe::Universe;
+
+/// The X,Y of each galaxy before expansion and the distances between them worked out for any expansion
+///
+pub struct Image {
+    pub galaxies: Vec<(i64, i64)>,
+    pub distances: Option<DistanceSum>,
+}
+
+impl Image {
+    pub fn new(galaxies: Vec<(i64, i64)>) -> Image {
+        let distances = DistanceSum::new(&galaxies);
+        Image { galaxies, distances }
+    }
+}
+
+/// The sum of the distances between every pair of galaxies is linear in the expansion factor, so it is
+/// split into the distance before expansion and the extra distance per unit of expansion - for each axis
+/// so columns and rows can expand by different amounts
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DistanceSum {
+    pub base_x: i64,
+    pub base_y: i64,
+    pub per_unit_x: i64,
+    pub per_unit_y: i64,
+}
+
+impl DistanceSum {
+    /// None if even the parts don't fit in i64
+    ///
+    pub fn new(galaxies: &[(i64, i64)]) -> Option<DistanceSum> {
+        let (base_x, per_unit_x) = axis_parts(galaxies.iter().map(|xy| xy.0).collect())?;
+        let (base_y, per_unit_y) = axis_parts(galaxies.iter().map(|xy| xy.1).collect())?;
+        Some(DistanceSum {
+            base_x,
+            base_y,
+            per_unit_x,
+            per_unit_y,
+        })
+    }
+
+    /// Each empty row and column has exp_factor more added next to it, or None if the total doesn't fit in i64
+    ///
+    pub fn total(&self, exp_factor: i64) -> Option<i64> {
+        self.total_per_axis(exp_factor, exp_factor)
+    }
+
+    /// Empty columns grow by col_factor and empty rows by row_factor, or None if the total doesn't fit in i64
+    ///
+    pub fn total_per_axis(&self, col_factor: i64, row_factor: i64) -> Option<i64> {
+        let x = col_factor.checked_mul(self.per_unit_x)?.checked_add(self.base_x)?;
+        let y = row_factor.checked_mul(self.per_unit_y)?.checked_add(self.base_y)?;
+        x.checked_add(y)
+    }
+
+    pub fn totals(&self, exp_factors: &[i64]) -> Vec<Option<i64>> {
+        exp_factors.iter().map(|&f| self.total(f)).collect()
+    }
+}
+
+/// Advent of code - Day 11
+///
+/// Part 1 - Sum the shortest dists between galaxies that are also expanding away
+/// Part 2 - As part 1 but the expansion is 1000000
+///
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input = Image;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        part1(input).ok_or_else(|| "Galaxies too far apart".to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        part2(input).ok_or_else(|| "Galaxies too far apart".to_string())
+    }
+}
+
+/// Galaxies are represented by #
+/// Find the X,Y of all galaxies
+///
+pub fn parse(data: &str) -> Result<Image, ParseError> {
+    let grid = Grid::parse_symbols(data, b"#.")?;
+
+    let galaxies = grid
+        .positions()
+        .filter(|&p| grid[p] == b'#')
+        .map(|(x, y)| (x as i64, y as i64))
+        .collect();
+
+    Ok(Image::new(galaxies))
+}
+
+/// Each empty row or column is doubled
+///
+pub fn part1(image: &Image) -> Option<i64> {
+    image.distances?.total(1)
+}
+
+/// Each empty row or column is replaced by 1000000
+///
+pub fn part2(image: &Image) -> Option<i64> {
+    image.distances?.total(999999)
+}
+
+/// Sum of the shortest distances between every pair of galaxies after expansion
+///
+/// Manhattan distance is the sum of the distances along each axis, so each axis can be summed on its own.
+/// With the values sorted the ith value is at least as far along as the i before it, so it adds
+/// i * value - (sum of the values before it) to the total. That's O(n log n) for the sort rather than
+/// O(n^2) for every pair. None if the sum doesn't fit in i64, the same as DistanceSum::total
+///
+pub fn sum_distances(image: &Image, exp_factor: i64) -> Option<i64> {
+    sum_distances_in(&expand(image, exp_factor)?, Metric::Manhattan)
+}
+
+/// The sum of the distances between each pair of points in turn, or None if it doesn't fit in i64
+///
+pub fn sum_distances_pairwise<const N: usize>(points: &[Point<N>], metric: Metric) -> Option<i64> {
+    let mut sum: i64 = 0;
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            sum = sum.checked_add(metric.distance(&points[i], &points[j])?)?;
+        }
+    }
+
+    Some(sum)
+}
+
+/// Find the rows and columns that have no galaxies
+/// Any galaxies that are to the right or bottom of an empty row or col need to expand by the factor.
+/// The galaxies stay in the same order as in the image. None if they are moved past what fits in i64
+///
+pub fn expand(image: &Image, exp_factor: i64) -> Option<Vec<Point<2>>> {
+    expand_per_axis(image, exp_factor, exp_factor)
+}
+
+/// As expand but empty columns grow by col_factor and empty rows by row_factor
+///
+pub fn expand_per_axis(image: &Image, col_factor: i64, row_factor: i64) -> Option<Vec<Point<2>>> {
+    let points: Vec<Point<2>> = image.galaxies.iter().map(|&(x, y)| [x, y]).collect();
+    expand_points(&points, [col_factor, row_factor])
+}
+
+/// The sum of the distances between every pair of values along one axis, and how much that grows for each unit of expansion
+///
+/// The growth is the same sum but over the number of empty lines before each value, which never goes down as the
+/// values go up so they are already in order
+///
+fn axis_parts(mut values: Vec<i64>) -> Option<(i64, i64)> {
+    values.sort_unstable();
+    Some((sorted_sum(&values)?, sorted_sum(&empty_before(&values)?)?))
+}
+
+/// Sum of the distances between every pair of values along one axis, or None if it doesn't fit in i64
+///
+pub(crate) fn axis_sum(mut values: Vec<i64>) -> Option<i64> {
+    values.sort_unstable();
+    sorted_sum(&values)
+}
+
+fn sorted_sum(values: &[i64]) -> Option<i64> {
+    let mut sum: i64 = 0;
+    let mut before: i64 = 0;
+    for (i, &v) in values.iter().enumerate() {
+        sum = sum.checked_add(v.checked_mul(i as i64)?.checked_sub(before)?)?;
+        before = before.checked_add(v)?;
+    }
+
+    Some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Lcg;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Some(374));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Some(82000210));
+    }
+
+    /// Galaxies scattered over a square map, with some rows and columns left empty
+    ///
+    fn random_image(num_galaxies: usize, size: i64) -> Image {
+        let mut lcg = Lcg::new(0x2023_0011);
+        let mut random = move |max: i64| lcg.below(max as u64) as i64;
+
+        let galaxies = (0..num_galaxies)
+            .map(|_| (random(size), random(size)))
+            .filter(|&(x, y)| x % 7 != 3 && y % 5 != 1)
+            .collect();
+        Image::new(galaxies)
+    }
+
+    #[test]
+    fn prefix_sums_match_pairwise() {
+        for image in [parse(EXAMPLE).unwrap(), random_image(2000, 300)] {
+            for factor in [0, 1, 9, 999999] {
+                let pairwise = sum_distances_pairwise(&expand(&image, factor).unwrap(), Metric::Manhattan);
+                assert_eq!(sum_distances(&image, factor), pairwise);
+            }
+        }
+    }
+
+    #[test]
+    fn totals_for_many_factors() {
+        let image = parse(EXAMPLE).unwrap();
+        let totals = image.distances.unwrap().totals(&[1, 9, 99, 999999]);
+        assert_eq!(totals, vec![Some(374), Some(1030), Some(8410), Some(82000210)]);
+
+        for image in [image, random_image(500, 100)] {
+            for (cols, rows) in [(0, 0), (1, 5), (7, 0), (999999, 3)] {
+                let pairwise = sum_distances_pairwise(&expand_per_axis(&image, cols, rows).unwrap(), Metric::Manhattan);
+                assert_eq!(image.distances.unwrap().total_per_axis(cols, rows), pairwise);
+            }
+        }
+    }
+
+    #[test]
+    fn totals_too_large_for_i64() {
+        //Galaxies on every other diagonal, so there's an empty row and column between each of them. Along
+        //each axis the pairs are 3 * |i - j| apart when the empty lines double, which adds up to n^3 - n
+        let n: i64 = 200_000;
+        let image = Image::new((0..n).map(|i| (2 * i, 2 * i)).collect());
+        let distances = image.distances.unwrap();
+        assert_eq!(distances.total(1), Some(n * n * n - n));
+        assert_eq!(distances.total(999999), None);
+        assert_eq!(distances.total_per_axis(0, 999999), None);
+        assert_eq!(sum_distances(&image, 1), distances.total(1));
+        assert_eq!(sum_distances(&image, 999999), None);
+        assert_eq!(Day11::part2(&image).err(), Some("Galaxies too far apart".to_string()));
+    }
+
+    #[test]
+    fn distances_between_numbered_galaxies() {
+        let universe = Universe::new(&parse(EXAMPLE).unwrap(), 1, Metric::Manhattan).unwrap();
+        assert_eq!(universe.distance(5, 9), Some(9));
+        assert_eq!(universe.distance(1, 7), Some(15));
+        assert_eq!(universe.distance(3, 6), Some(17));
+        assert_eq!(universe.distance(8, 9), Some(5));
+        assert_eq!(universe.distance(0, 1), None);
+        assert_eq!(universe.distance(1, 10), None);
+    }
+
+    #[test]
+    fn nearest_galaxies() {
+        let universe = Universe::new(&parse(EXAMPLE).unwrap(), 1, Metric::Manhattan).unwrap();
+        assert_eq!(universe.nearest(8, 3), Some(vec![(9, 5), (5, 6), (3, 9)]));
+        assert_eq!(universe.nearest(1, 100).map(|n| n.len()), Some(8));
+        assert_eq!(universe.nearest(10, 1), None);
+    }
+
+    #[test]
+    fn farthest_pair_matches_every_pair() {
+        for image in [parse(EXAMPLE).unwrap(), random_image(300, 80)] {
+            for metric in Metric::ALL {
+                let universe = Universe::new(&image, 9, metric).unwrap();
+                let mut farthest = 0;
+                for a in 1..=universe.len() {
+                    for b in a + 1..=universe.len() {
+                        farthest = farthest.max(universe.distance(a, b).unwrap());
+                    }
+                }
+
+                let (a, b, dist) = universe.farthest_pair().unwrap();
+                assert_eq!(dist, farthest, "{:?}", metric);
+                assert_eq!(universe.distance(a, b), Some(dist));
+            }
+        }
+    }
+
+    #[test]
+    fn expand_keeps_image_order() {
+        let image = Image::new(vec![(2, 2), (0, 0), (2, 0)]);
+        assert_eq!(expand(&image, 1), Some(vec![[3, 3], [0, 0], [3, 0]]));
+        assert_eq!(expand_per_axis(&image, 0, 9), Some(vec![[2, 11], [0, 0], [2, 0]]));
+        assert_eq!(Universe::new(&image, 1, Metric::Manhattan).map(|u| u.galaxies), expand(&image, 1));
+    }
+
+    #[test]
+    fn universe_with_other_metrics() {
+        let image = parse("#.#\n...\n..#").unwrap();
+        let universe = Universe::new(&image, 1, Metric::from_arg("chebyshev").unwrap()).unwrap();
+        assert_eq!(universe.distance(1, 3), Some(3));
+        assert_eq!(universe.nearest(1, 2), Some(vec![(2, 3), (3, 3)]));
+
+        let universe = Universe::new(&image, 1, Metric::from_arg("euclidean2").unwrap()).unwrap();
+        assert_eq!(universe.distance(1, 3), Some(18));
+        assert_eq!(universe.farthest_pair(), Some((1, 3, 18)));
+        assert_eq!(universe.distance_csv(), ",1,2,3\n1,0,9,18\n2,9,0,9\n3,18,9,0\n");
+        assert_eq!(Metric::from_arg("euclidean"), None);
+    }
+
+    #[test]
+    fn distance_matrix() {
+        let universe = Universe::new(&parse("#.#\n...\n..#").unwrap(), 1, Metric::Manhattan).unwrap();
+        assert_eq!(universe.distance_csv(), ",1,2,3\n1,0,3,6\n2,3,0,3\n3,6,3,0\n");
+    }
+
+    #[test]
+    fn two_dimensions_match_the_puzzle() {
+        let image = parse(EXAMPLE).unwrap();
+        let points: Vec<Point<2>> = image.galaxies.iter().map(|&(x, y)| [x, y]).collect();
+
+        let sum = |factors| sum_distances_in(&expand_points(&points, factors).unwrap(), Metric::Manhattan);
+        assert_eq!(sum([1, 1]), Some(374));
+        assert_eq!(sum([999999, 999999]), Some(82000210));
+        assert_eq!(sum([3, 7]), image.distances.unwrap().total_per_axis(3, 7));
+    }
+
+    #[test]
+    fn metrics_match_pairwise() {
+        let voxels: Vec<Point<3>> = random_image(300, 40)
+            .galaxies
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| [x, y, (i as i64 * 7) % 13])
+            .collect();
+
+        for factors in [[0, 0, 0], [1, 2, 3], [99, 0, 9]] {
+            let expanded = expand_points(&voxels, factors).unwrap();
+            for metric in Metric::ALL {
+                let pairwise = sum_distances_pairwise(&expanded, metric);
+                assert_eq!(sum_distances_in(&expanded, metric), pairwise, "{:?}", metric);
+            }
+        }
+    }
+
+    #[test]
+    fn expands_voxel_slices() {
+        let voxels = parse_voxels("#..\n...\n\n...\n...\n\n..#\n...").unwrap();
+        assert_eq!(voxels, vec![[0, 0, 0], [2, 0, 2]]);
+        assert_eq!(expand_points(&voxels, [1, 1, 1]), Some(vec![[0, 0, 0], [3, 0, 3]]));
+        assert_eq!(Metric::Chebyshev.distance(&[0, 0, 0], &[3, 0, 3]), Some(3));
+        assert_eq!(Metric::SquaredEuclidean.distance(&[0, 0, 0], &[3, 0, 3]), Some(18));
+
+        let err = parse_voxels("#.\n..\n\n.x").err().unwrap();
+        assert_eq!(err.line, 4);
+
+        let crlf = parse_voxels("#..\r\n...\r\n\r\n...\r\n...\r\n\r\n..#\r\n...\r\n").unwrap();
+        assert_eq!(crlf, voxels);
+    }
+
+    #[test]
+    fn expands_by_any_factor() {
+        let image = parse(EXAMPLE).unwrap();
+        assert_eq!(sum_distances(&image, 9), Some(1030));
+        assert_eq!(sum_distances(&image, 99), Some(8410));
+    }
+
+    #[test]
+    fn points_too_far_apart_for_i64() {
+        //Each pair is only 3e9 apart along an axis but the squares of that add up to more than i64 holds
+        let points: Vec<Point<2>> = vec![[0, 0], [3_000_000_000, 0], [0, 3_000_000_000]];
+        assert_eq!(Metric::SquaredEuclidean.distance(&points[0], &points[1]), Some(9_000_000_000_000_000_000));
+        assert_eq!(Metric::SquaredEuclidean.distance(&points[1], &points[2]), None);
+        assert_eq!(sum_distances_in(&points, Metric::SquaredEuclidean), None);
+        assert_eq!(sum_distances_pairwise(&points, Metric::SquaredEuclidean), None);
+        assert_eq!(sum_distances_in(&points, Metric::Manhattan), Some(12_000_000_000));
+
+        assert_eq!(Metric::Manhattan.distance(&[i64::MIN], &[i64::MAX]), None);
+        assert_eq!(Metric::Chebyshev.distance(&[0, i64::MIN], &[0, 0]), None);
+        assert_eq!(sum_distances_in(&[[i64::MIN], [0], [i64::MAX]], Metric::Manhattan), None);
+
+        //One empty line moves the second point half way to the limit, three take it past
+        assert_eq!(expand_points(&[[0], [2]], [i64::MAX / 2]), Some(vec![[0], [i64::MAX / 2 + 2]]));
+        assert_eq!(expand_points(&[[0], [4]], [i64::MAX / 2]), None);
+
+        let image = Image::new(vec![(0, 0), (2, 0)]);
+        assert!(Universe::new(&image, 4_000_000_000, Metric::Manhattan).is_some());
+        assert!(Universe::new(&image, 4_000_000_000, Metric::SquaredEuclidean).is_none());
+        assert_eq!(sum_distances(&image, i64::MAX), None);
+    }
+}
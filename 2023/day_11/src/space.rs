@@ -0,0 +1,138 @@
+use crate::{axis_sum, sum_distances_pairwise};
+use aoc_common::{blocks, Grid, ParseError};
+
+/// A point with N coordinates, e.g. [x, y] or [x, y, z]
+///
+pub type Point<const N: usize> = [i64; N];
+
+/// How the distance between two points is measured
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// Sum of the distances along each axis - moving one axis at a time as in the puzzle
+    Manhattan,
+    /// The largest distance along any axis - moving diagonally costs the same as straight
+    Chebyshev,
+    /// Sum of the squares of the distances along each axis, which stays a whole number
+    SquaredEuclidean,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 3] = [Metric::Manhattan, Metric::Chebyshev, Metric::SquaredEuclidean];
+
+    pub fn from_arg(arg: &str) -> Option<Metric> {
+        match arg {
+            "manhattan" => Some(Metric::Manhattan),
+            "chebyshev" => Some(Metric::Chebyshev),
+            "euclidean2" => Some(Metric::SquaredEuclidean),
+            _ => None,
+        }
+    }
+
+    /// The distance between two points, or None if it doesn't fit in i64
+    ///
+    pub fn distance<const N: usize>(self, a: &Point<N>, b: &Point<N>) -> Option<i64> {
+        let mut dist: i64 = 0;
+        for (p, q) in a.iter().zip(b) {
+            let delta = p.checked_sub(*q)?.checked_abs()?;
+            dist = match self {
+                Metric::Manhattan => dist.checked_add(delta)?,
+                Metric::Chebyshev => dist.max(delta),
+                Metric::SquaredEuclidean => dist.checked_add(delta.checked_mul(delta)?)?,
+            };
+        }
+        Some(dist)
+    }
+}
+
+/// Each empty line along an axis (a column, a row, a slice...) has that axis's factor more added next to it.
+/// The points stay in the same order. None if any of them are moved past what fits in i64
+///
+pub fn expand_points<const N: usize>(points: &[Point<N>], factors: [i64; N]) -> Option<Vec<Point<N>>> {
+    let mut expanded = points.to_vec();
+    for (axis, factor) in factors.into_iter().enumerate() {
+        let values = expand_axis(points.iter().map(|p| p[axis]).collect(), factor)?;
+        for (p, v) in expanded.iter_mut().zip(values) {
+            p[axis] = v;
+        }
+    }
+    Some(expanded)
+}
+
+/// The sum of the distances between every pair of points
+///
+/// Manhattan and squared Euclidean distances are sums over the axes so each axis is done on its own - with
+/// sorted prefix sums for Manhattan, and n * (sum of squares) - (sum)^2 for squared Euclidean. Chebyshev
+/// distance doesn't split by axis so every pair is checked. None if the sum doesn't fit in i64
+///
+pub fn sum_distances_in<const N: usize>(points: &[Point<N>], metric: Metric) -> Option<i64> {
+    let axis = |a: usize| points.iter().map(move |p| p[a]);
+    match metric {
+        Metric::Manhattan => (0..N).try_fold(0i64, |sum, a| sum.checked_add(axis_sum(axis(a).collect())?)),
+        Metric::SquaredEuclidean => {
+            //The squares get big quickly so work in i128 and only check the answer fits at the end
+            let n = points.len() as i128;
+            let mut sum: i128 = 0;
+            for a in 0..N {
+                let mut total: i128 = 0;
+                let mut squares: i128 = 0;
+                for v in axis(a).map(i128::from) {
+                    total = total.checked_add(v)?;
+                    squares = squares.checked_add(v * v)?;
+                }
+                sum = sum.checked_add(n.checked_mul(squares)?.checked_sub(total.checked_mul(total)?)?)?;
+            }
+            i64::try_from(sum).ok()
+        }
+        Metric::Chebyshev => sum_distances_pairwise(points, metric),
+    }
+}
+
+/// Slices of a 3D image separated by blank lines, each a grid of # and . like the puzzle input.
+/// The slice number is z
+///
+pub fn parse_voxels(data: &str) -> Result<Vec<Point<3>>, ParseError> {
+    let mut voxels = Vec::new();
+
+    for (z, (line_offset, block)) in blocks(data).into_iter().enumerate() {
+        let grid = Grid::parse_symbols(block, b"#.").map_err(|e| e.offset_lines(line_offset))?;
+        let slice = grid.positions().filter(|&p| grid[p] == b'#');
+        voxels.extend(slice.map(|(x, y)| [x as i64, y as i64, z as i64]));
+    }
+
+    Ok(voxels)
+}
+
+/// Moves each value along by exp_factor for every empty line before it, keeping them in the same order.
+/// None if any value is moved past what fits in i64
+///
+fn expand_axis(values: Vec<i64>, exp_factor: i64) -> Option<Vec<i64>> {
+    let mut sorted = values.clone();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let empty_before = empty_before(&sorted)?;
+
+    values
+        .into_iter()
+        .map(|v| {
+            let i = sorted.binary_search(&v).expect("Value is in the sorted list");
+            v.checked_add(empty_before[i].checked_mul(exp_factor)?)
+        })
+        .collect()
+}
+
+/// The number of empty lines before each of the sorted values, or None if the values are too far apart to count
+///
+pub(crate) fn empty_before(sorted: &[i64]) -> Option<Vec<i64>> {
+    let mut num_empty: i64 = 0;
+    let mut empty_before = Vec::with_capacity(sorted.len());
+    for i in 0..sorted.len() {
+        if i > 0 {
+            num_empty = num_empty.checked_add((sorted[i].checked_sub(sorted[i - 1])? - 1).max(0))?;
+        }
+        empty_before.push(num_empty);
+    }
+
+    Some(empty_before)
+}
@@ -0,0 +1,121 @@
+use crate::{expand, Image, Metric, Point};
+use std::fmt::Write;
+
+/// The galaxies after expansion, numbered from 1 in reading order as in the puzzle
+///
+pub struct Universe {
+    pub galaxies: Vec<Point<2>>,
+    pub metric: Metric,
+}
+
+impl Universe {
+    /// Each empty row and column has exp_factor more added next to it, and distances are measured with metric.
+    /// None if the galaxies end up too far apart for the distances between them to fit in i64
+    ///
+    pub fn new(image: &Image, exp_factor: i64, metric: Metric) -> Option<Universe> {
+        let galaxies = expand(image, exp_factor)?;
+
+        //No two galaxies are further apart than the opposite corners of the box around them all
+        let min = [0, 1].map(|a| galaxies.iter().map(|p| p[a]).min().unwrap_or(0));
+        let max = [0, 1].map(|a| galaxies.iter().map(|p| p[a]).max().unwrap_or(0));
+        metric.distance(&min, &max)?;
+
+        Some(Universe { galaxies, metric })
+    }
+
+    pub fn len(&self) -> usize {
+        self.galaxies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.galaxies.is_empty()
+    }
+
+    /// The shortest distance between two galaxies by number, or None if there aren't that many
+    ///
+    pub fn distance(&self, a: usize, b: usize) -> Option<i64> {
+        Some(self.between(&self.get(a)?, &self.get(b)?))
+    }
+
+    /// The k galaxies closest to the given one as (number, distance), closest first. Galaxies the same
+    /// distance away are in number order
+    ///
+    pub fn nearest(&self, galaxy: usize, k: usize) -> Option<Vec<(usize, i64)>> {
+        let from = self.get(galaxy)?;
+        let mut others: Vec<(usize, i64)> = self
+            .numbered()
+            .filter(|&(n, _)| n != galaxy)
+            .map(|(n, pos)| (n, self.between(&from, &pos)))
+            .collect();
+
+        others.sort_by_key(|&(n, dist)| (dist, n));
+        others.truncate(k);
+        Some(others)
+    }
+
+    /// The two galaxies furthest apart and the distance between them
+    ///
+    /// The manhattan distance is the largest of |(x + y) - (x' + y')| and |(x - y) - (x' - y')|, and the
+    /// chebyshev distance the largest of |x - x'| and |y - y'|, so for those the furthest pair is at the
+    /// extremes of one of them - no need to check every pair. Squared euclidean has no such shortcut
+    ///
+    pub fn farthest_pair(&self) -> Option<(usize, usize, i64)> {
+        if self.galaxies.len() < 2 {
+            return None;
+        }
+
+        let farthest_first = |&(a, b, dist): &(usize, usize, i64)| (dist, std::cmp::Reverse((a, b)));
+        let extremes: [fn(Point<2>) -> i128; 2] = match self.metric {
+            Metric::Manhattan => [|[x, y]| x as i128 + y as i128, |[x, y]| x as i128 - y as i128],
+            Metric::Chebyshev => [|[x, _]| x as i128, |[_, y]| y as i128],
+            Metric::SquaredEuclidean => {
+                return self
+                    .numbered()
+                    .flat_map(|(a, p)| self.numbered().skip(a).map(move |(b, q)| (a, b, self.between(&p, &q))))
+                    .max_by_key(farthest_first);
+            }
+        };
+
+        extremes
+            .iter()
+            .map(|extreme| {
+                let min = self.numbered().min_by_key(|&(_, pos)| extreme(pos)).unwrap();
+                let max = self.numbered().max_by_key(|&(_, pos)| extreme(pos)).unwrap();
+                (min.0.min(max.0), min.0.max(max.0), self.between(&min.1, &max.1))
+            })
+            .max_by_key(farthest_first)
+    }
+
+    /// The distance between every pair of galaxies as CSV with a header row and column of galaxy numbers.
+    /// This is n^2 so only for small maps
+    ///
+    pub fn distance_csv(&self) -> String {
+        let mut csv = String::new();
+        for n in 1..=self.galaxies.len() {
+            write!(csv, ",{}", n).unwrap();
+        }
+        csv.push('\n');
+
+        for (n, a) in self.numbered() {
+            write!(csv, "{}", n).unwrap();
+            for &b in &self.galaxies {
+                write!(csv, ",{}", self.between(&a, &b)).unwrap();
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    fn between(&self, a: &Point<2>, b: &Point<2>) -> i64 {
+        self.metric.distance(a, b).expect("Distances were checked to fit when the universe was made")
+    }
+
+    fn get(&self, galaxy: usize) -> Option<Point<2>> {
+        self.galaxies.get(galaxy.checked_sub(1)?).copied()
+    }
+
+    fn numbered(&self) -> impl Iterator<Item = (usize, Point<2>)> + '_ {
+        self.galaxies.iter().enumerate().map(|(i, &pos)| (i + 1, pos))
+    }
+}
@@ -0,0 +1,243 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// A row of springs ('?' unknown, '.' working, '#' damaged) and the sizes of the damaged groups
+///
+pub struct Record {
+    pub springs: Vec<char>,
+    pub counts: Vec<usize>,
+}
+
+//Limits so that the unfolded record still fits in the cache key
+const MAX_SPRINGS: usize = 20;
+const MAX_COUNTS: usize = 6;
+const MAX_COUNT_SIZE: usize = 15;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct CacheKey {
+    springs_1: u128,
+    springs_2: u128,
+    springs_3: u64,
+    counts: u128,
+    num_in_group: usize,
+}
+
+/// Advent of code - Day 12
+///
+/// Part 1 - Find all possible combinations of broken springs given partial data
+/// Part 2 - As part 1 but repeat the sequences 5 times joined by a '?'
+///
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input = Vec<Record>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+/// FORMAT: ???.### 1,1,3
+///
+/// The cache key packs the unfolded springs and counts into fixed size ints so that puts a limit on the sizes
+///
+pub fn parse(data: &str) -> Result<Vec<Record>, ParseError> {
+    let mut records = Vec::new();
+
+    for (i, line) in data.lines().enumerate() {
+        let line_num = i + 1;
+        let (l, r) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(line_num, line, line, "Expected springs and counts separated by a space"))?;
+
+        if let Some(pos) = l.find(|c| !matches!(c, '?' | '.' | '#')) {
+            return Err(ParseError::at(line_num, line, &l[pos..pos + 1], "Incorrect spring symbol"));
+        }
+        if l.len() > MAX_SPRINGS {
+            return Err(ParseError::at(line_num, line, l, format!("More than {} springs", MAX_SPRINGS)));
+        }
+
+        let counts = r
+            .split(',')
+            .map(|n| parse_field::<usize>(line_num, line, n))
+            .collect::<Result<Vec<usize>, _>>()?;
+        if counts.len() > MAX_COUNTS || counts.iter().any(|&c| c > MAX_COUNT_SIZE) {
+            let msg = format!("Expected up to {} counts of at most {}", MAX_COUNTS, MAX_COUNT_SIZE);
+            return Err(ParseError::at(line_num, line, r, msg));
+        }
+
+        records.push(Record {
+            springs: l.chars().collect(),
+            counts,
+        });
+    }
+
+    Ok(records)
+}
+
+/// Sum the possible arrangements of each record as given
+///
+pub fn part1(records: &[Record]) -> usize {
+    records.iter().map(count_arrangements).sum()
+}
+
+/// Sum the possible arrangements of each record once unfolded
+///
+pub fn part2(records: &[Record]) -> usize {
+    records.iter().map(|r| count_arrangements(&unfold(r))).sum()
+}
+
+/// Unfold - Part 2 repeats 5 times, joned with '?'
+///
+pub fn unfold(record: &Record) -> Record {
+    let mut springs: Vec<char> = Vec::with_capacity((record.springs.len() + 1) * 5);
+    let mut counts: Vec<usize> = Vec::with_capacity(record.counts.len() * 5);
+    for _ in 0..4 {
+        springs.extend_from_slice(&record.springs);
+        springs.push('?');
+        counts.extend_from_slice(&record.counts);
+    }
+    springs.extend_from_slice(&record.springs);
+    counts.extend_from_slice(&record.counts);
+
+    Record { springs, counts }
+}
+
+/// Given a sequence ???.### where '?' is unknown status, '.' is working and '#' is damaged
+/// and a sequence 1,1,3 showing the number of sequential damaged. Calculate the number of possible resolved states of '?'
+/// that support the sequential damage
+///
+pub fn count_arrangements(record: &Record) -> usize {
+    //Add this to terminate so we don't have to check for specific end case
+    let mut springs = record.springs.clone();
+    springs.push('.');
+
+    let mut cache: HashMap<CacheKey, usize> = HashMap::new();
+    recurse_possibilities(&springs, &record.counts, 0, &mut cache)
+}
+
+/// Explore all possible solutions recursively. We cache already explored solutions so we don't need to recalculate (memoisation)
+///
+fn recurse_possibilities(springs_slice: &[char], counts: &[usize], num_in_group: usize, cache: &mut HashMap<CacheKey, usize>) -> usize {
+    if springs_slice.is_empty() {
+        return if counts.is_empty() && num_in_group == 0 { 1 } else { 0 };
+    }
+
+    let key = create_cache_key(springs_slice, counts, num_in_group);
+    let cache_hit = cache.get(&key);
+    if let Some(&x) = cache_hit {
+        return x;
+    }
+
+    let mut possibilities: [char; 2] = ['0', '0'];
+    let num_possibilities: usize;
+
+    if springs_slice[0] == '?' {
+        possibilities[0] = '.';
+        possibilities[1] = '#';
+        num_possibilities = 2;
+    } else {
+        possibilities[0] = springs_slice[0];
+        num_possibilities = 1;
+    }
+
+    let mut sum: usize = 0;
+    for p in possibilities.iter().take(num_possibilities) {
+        match p {
+            '.' => {
+                if num_in_group > 0 {
+                    if !counts.is_empty() && counts[0] == num_in_group {
+                        sum += recurse_possibilities(&springs_slice[1..], &counts[1..], 0, cache);
+                    }
+                } else {
+                    sum += recurse_possibilities(&springs_slice[1..], counts, 0, cache);
+                }
+            }
+            '#' => sum += recurse_possibilities(&springs_slice[1..], counts, num_in_group + 1, cache),
+            _ => unreachable!(), //Checked when parsing
+        }
+    }
+
+    cache.insert(key, sum);
+
+    sum
+}
+
+/// Encode the parameters for the recurse function into a key that we can use to look up the cache
+/// to find any previously calculated value
+///
+fn create_cache_key(springs_slice: &[char], counts: &[usize], num_in_group: usize) -> CacheKey {
+    let mut key = CacheKey {
+        springs_1: 0,
+        springs_2: 0,
+        springs_3: 0,
+        counts: 0,
+        num_in_group,
+    };
+
+    for (i, &s) in springs_slice.iter().enumerate() {
+        let e = match s {
+            '#' => 0b01,
+            '.' => 0b10,
+            '?' => 0b11,
+            _ => unreachable!(), //Checked when parsing
+        };
+
+        match i {
+            0..=42 => key.springs_1 |= (e as u128) << (3 * i),
+            43..=84 => key.springs_2 |= (e as u128) << (3 * (i - 42)),
+            _ => key.springs_3 |= (e as u64) << (3 * (i - 84)),
+        }
+    }
+
+    for (i, &c) in counts.iter().enumerate() {
+        key.counts |= (c as u128) << (4 * i);
+    }
+
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 525152);
+    }
+
+    #[test]
+    fn counts_each_record() {
+        let records = parse(EXAMPLE).unwrap();
+        let counts: Vec<usize> = records.iter().map(count_arrangements).collect();
+        assert_eq!(counts, [1, 4, 1, 1, 4, 10]);
+
+        let unfolded: Vec<usize> = records.iter().map(|r| count_arrangements(&unfold(r))).collect();
+        assert_eq!(unfolded, [1, 16384, 1, 16, 2500, 506250]);
+    }
+
+    #[test]
+    fn reports_unknown_symbols() {
+        let e = parse("???.### 1,1,3\n.?x 1").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+    }
+}
@@ -0,0 +1,233 @@
+use aoc_common::{blocks, Grid, ParseError, Solution};
+use std::fmt::Display;
+
+/// Each row and column packed into bits with '#' set
+///
+pub struct Pattern {
+    pub row_masks: Vec<u32>,
+    pub col_masks: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq)]
+enum CmpResult {
+    Eql,
+    Smudged,
+    NotEql,
+}
+
+/// Advent of code - Day 13
+///
+/// Part 1 - Find the number of mirrored rows and cols
+/// Part 2 - Find the "smudges" that if changed would cause a different reflection
+///
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Pattern>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+/// Patterns are separated by a blank line
+///
+pub fn parse(data: &str) -> Result<Vec<Pattern>, ParseError> {
+    blocks(data)
+        .into_iter()
+        .map(|(line_offset, block)| parse_pattern(block).map_err(|e| e.offset_lines(line_offset)))
+        .collect()
+}
+
+/// Sum the reflections of all the patterns
+///
+pub fn part1(patterns: &[Pattern]) -> usize {
+    patterns.iter().map(|p| summarise(p, false)).sum()
+}
+
+/// Sum the reflections of all the patterns once the smudge has been cleaned
+///
+pub fn part2(patterns: &[Pattern]) -> usize {
+    patterns.iter().map(|p| summarise(p, true)).sum()
+}
+
+/// Pack the rows and columns into a single int so we can compare rows with a single comparison
+///
+pub fn parse_pattern(pattern_block: &str) -> Result<Pattern, ParseError> {
+    let grid = Grid::parse_with(pattern_block, |b| match b {
+        b'#' => Some(1_u32),
+        b'.' => Some(0_u32),
+        _ => None,
+    })?;
+
+    //Only 32 bits to pack each row and column into
+    if grid.width() > 32 {
+        return Err(ParseError::new(1, 33, "", "Pattern is more than 32 wide"));
+    }
+    if grid.height() > 32 {
+        return Err(ParseError::new(33, 1, "", "Pattern is more than 32 high"));
+    }
+
+    Ok(Pattern {
+        row_masks: grid.rows().map(|r| pack(r.iter())).collect(),
+        col_masks: (0..grid.width()).map(|x| pack(grid.column(x))).collect(),
+    })
+}
+
+fn pack<'a>(cells: impl Iterator<Item = &'a u32>) -> u32 {
+    cells.enumerate().fold(0, |packed, (i, c)| packed | c << i)
+}
+
+/// Check for rows and columns that are next to each other that match
+/// These are potential reflection boundaries. We then work out from there to check that
+/// the surrounding rows or columns are reflected
+///
+/// We pass in the compare function because for part 2 for every pair of rows or columns we check
+/// to see if changing a single bit would make a reflection. There needs to be at least one "smudge"
+///
+pub fn summarise(p: &Pattern, smudge_required: bool) -> usize {
+    let c = if smudge_required {
+        solve_single_dir_smudged(&p.col_masks)
+    } else {
+        solve_single_dir(&p.col_masks)
+    };
+    if c > 0 {
+        return c;
+    }
+
+    let r = if smudge_required {
+        solve_single_dir_smudged(&p.row_masks)
+    } else {
+        solve_single_dir(&p.row_masks)
+    };
+    r * 100
+}
+
+/// Allows us to solve for rows or cols with a single function
+/// Counts the number of cols/rows that are left/above the found reflection
+///
+fn solve_single_dir(masks: &[u32]) -> usize {
+    'outer: for c in 0..masks.len() - 1 {
+        if masks[c] == masks[c + 1] {
+            let m = c.min(masks.len() - 2 - c);
+            for i in 0..m {
+                if masks[c - (i + 1)] != masks[c + (i + 2)] {
+                    continue 'outer;
+                }
+            }
+
+            return c + 1;
+        }
+    }
+
+    0
+}
+
+/// Allows us to solve for rows or cols with a single function
+/// Counts the number of cols/rows that are left/above the found reflection
+///
+/// This is the smudged version so will consider any row/col where a single change would 
+/// allow a reflection
+///
+fn solve_single_dir_smudged(masks: &[u32]) -> usize {
+    'outer: for c in 0..masks.len() - 1 {
+        let cmp = compare(masks[c], masks[c + 1]);
+        let mut smudged = cmp == CmpResult::Smudged;
+
+        if matches!(cmp, CmpResult::Smudged | CmpResult::Eql) {
+            let m = c.min(masks.len() - 2 - c);
+            for i in 0..m {
+                let cmp = compare(masks[c - (i + 1)], masks[c + (i + 2)]);
+                match cmp {
+                    CmpResult::NotEql => continue 'outer,
+                    CmpResult::Eql => {}
+                    CmpResult::Smudged => {
+                        if smudged {
+                            continue 'outer;
+                        } else {
+                            smudged = true;
+                        }
+                    }
+                }
+            }
+
+            if smudged {
+                return c + 1;
+            }
+        }
+    }
+
+    0
+}
+
+fn compare(a: u32, b: u32) -> CmpResult {
+    if a == b {
+        return CmpResult::Eql;
+    }
+
+    //Check if number is a Po2 which means a single bit is set
+    let val = a ^ b;
+    if val & (val - 1) == 0 {
+        return CmpResult::Smudged;
+    }
+
+    CmpResult::NotEql
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 405);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 400);
+    }
+
+    #[test]
+    fn summarises_each_pattern() {
+        let patterns = parse(EXAMPLE).unwrap();
+        assert_eq!(summarise(&patterns[0], false), 5);
+        assert_eq!(summarise(&patterns[1], false), 400);
+        assert_eq!(summarise(&patterns[0], true), 300);
+        assert_eq!(summarise(&patterns[1], true), 100);
+    }
+
+    #[test]
+    fn compares_masks() {
+        assert_eq!(compare(0b1011, 0b1011), CmpResult::Eql);
+        assert_eq!(compare(0b1011, 0b1001), CmpResult::Smudged);
+        assert_eq!(compare(0b1011, 0b0111), CmpResult::NotEql);
+    }
+
+    #[test]
+    fn reports_line_in_later_pattern() {
+        let e = parse("#.\n.#\n\n##\n#x").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (5, 2, "x"));
+    }
+
+    #[test]
+    fn accepts_crlf() {
+        assert_eq!(part1(&parse(&EXAMPLE.replace('\n', "\r\n")).unwrap()), 405);
+
+        let e = parse("#.\r\n.#\r\n\r\n##\r\n#x\r\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (5, 2, "x"));
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_common::{Dir, Grid, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// 'O' rolling rocks, '#' fixed rocks and '.' empty
+///
+pub struct Platform {
+    pub tiles: Grid<u8>,
+}
+
+/// Advent of code - Day 14
+///
+/// Part 1 - Tilt rocks north until they cannot move and then count the number at each level
+/// Part 2 - Tilt in cycles - each cycle tips N,W,S,E run for 1000000000 cycles
+///
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input = Platform;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+pub fn parse(data: &str) -> Result<Platform, ParseError> {
+    Ok(Platform {
+        tiles: Grid::parse_symbols(data, b"O#.")?,
+    })
+}
+
+/// Tilt north once and count the load
+///
+pub fn part1(platform: &Platform) -> usize {
+    let mut grid = platform.tiles.clone();
+    tilt_cycle(&mut grid, 1, &[Dir::Up])
+}
+
+/// Spin through the full N,W,S,E cycle 1000000000 times and count the load
+///
+pub fn part2(platform: &Platform) -> usize {
+    let mut grid = platform.tiles.clone();
+    let cycles_full = [Dir::Up, Dir::Left, Dir::Down, Dir::Right]; //N,W,S,E
+    tilt_cycle(&mut grid, 1000000000, &cycles_full)
+}
+
+/// Just a naive simulation of the cycle - tilting as far as we can go in each direction until no rocks move and
+/// then moving to the next direction in the cycle.
+///
+/// For long sycles we find the period after which the cycle repeats which allows us to "skip" forward and shortcut all the cycles
+///
+/// We then sum the rolling rocks on each row multiplied by the row factor
+///
+fn tilt_cycle(grid: &mut Grid<u8>, num_cycles: usize, cycles: &[Dir]) -> usize {
+    let mut cycle_cache: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut found_period = false;
+
+    let mut cycle_num: usize = 0;
+    while cycle_num < num_cycles {
+        for &dir in cycles {
+            loop {
+                let mut moved = false;
+
+                for p in grid.positions() {
+                    if grid[p] == b'O' {
+                        if let Some(new_p) = grid.step(p, dir) {
+                            if grid[new_p] == b'.' {
+                                grid[p] = b'.';
+                                grid[new_p] = b'O';
+                                moved = true;
+                            }
+                        }
+                    }
+                }
+
+                if !moved {
+                    break;
+                }
+            }
+        }
+
+        if !found_period {
+            let cache_hit = cycle_cache.get(grid.cells());
+            if let Some(period_start) = cache_hit {
+                let period = cycle_num - period_start;
+                cycle_num += ((num_cycles - cycle_num) / period) * period;
+                found_period = true;
+            } else {
+                cycle_cache.insert(grid.cells().to_vec(), cycle_num);
+            }
+        }
+
+        cycle_num += 1;
+    }
+
+    //Count the rolling rocks on each row - top row multiplied by N, bottom row by 1
+    let height = grid.height();
+    let mut sum: usize = 0;
+    for (i, l) in grid.rows().enumerate() {
+        sum += (height - i) * l.iter().filter(|&c| *c == b'O').count();
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 136);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 64);
+    }
+}
@@ -0,0 +1,194 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use std::collections::LinkedList;
+use std::fmt::Display;
+
+/// The action to take on the box selected by the label
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Op {
+    Remove,
+    Insert(u8),
+}
+
+/// A single step "rn=1" => label "rn", insert focal length 1
+///
+pub struct Step {
+    pub text: String,
+    pub label: String,
+    pub op: Op,
+}
+
+/// Advent of code - Day 15
+///
+/// Part 1 - Apply HASH algorithm to strings
+/// Part 2 - Process the steps and fill boxes with lenses
+///
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Step>;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+/// FORMAT: rn=1,cm-,qp=3
+///
+/// Labels are packed into a u64 so can be at most 8 characters
+///
+pub fn parse(data: &str) -> Result<Vec<Step>, ParseError> {
+    let line = data.lines().next().ok_or_else(|| ParseError::at_end(data, "No steps"))?;
+    line.split(',')
+        .map(|s| {
+            let (label, v) = s
+                .split_once(['-', '='])
+                .ok_or_else(|| ParseError::at(1, line, s, "Expected a '-' or '=' operation"))?;
+            if label.is_empty() || label.len() > 8 {
+                return Err(ParseError::at(1, line, s, "Label must be 1 to 8 characters"));
+            }
+
+            let op = match s.as_bytes()[label.len()] {
+                b'-' => Op::Remove,
+                _ => Op::Insert(parse_field::<u8>(1, line, v)?),
+            };
+
+            Ok(Step {
+                text: s.to_string(),
+                label: label.to_string(),
+                op,
+            })
+        })
+        .collect()
+}
+
+/// Run our HASH algorithm on all the instruction steps in the input and sum the result
+///
+pub fn part1(steps: &[Step]) -> usize {
+    steps.iter().map(|s| hash(s.text.as_bytes())).sum()
+}
+
+pub fn part2(steps: &[Step]) -> usize {
+    run_boxing(steps)
+}
+
+/// Run through the steps and fetch the label "rn=1" => "rn"
+/// HASH the label to get the box number
+/// Get the action (either = or -)
+/// If - then look into the box and remove the lens with the label if present (making sure to close up any gaps left)
+/// If = then parse the focal length "rn=1" => 1 if a lens with the same label exists in the box then replace it with the new focal length otherwise add to end of box
+///
+/// Then calculate the focusing power of all lenses and return
+///
+fn run_boxing(steps: &[Step]) -> usize {
+    //256 boxes with variable number of lenses in each (order needs to be maintained)
+    //Went linked list because we are adding and removing and filling gaps
+    let mut boxes: Vec<LinkedList<(u64, u8)>> = Vec::with_capacity(256);
+    boxes.resize(256, LinkedList::new());
+
+    for s in steps {
+        let bytes = s.label.as_bytes();
+        let label_id = encode_label(bytes);
+        let box_idx = hash(bytes);
+
+        match s.op {
+            Op::Remove => { extract_if(&mut boxes[box_idx], label_id); },
+            Op::Insert(focal_len) => {
+                if let Some((_, existing_fl)) = boxes[box_idx].iter_mut().find(|l| l.0 == label_id) {
+                    //Replace if exists
+                    *existing_fl = focal_len;
+                } else {
+                    //...add if not
+                    boxes[box_idx].push_back((label_id, focal_len));
+                }
+            }
+        }
+    }
+
+    //Calculate focusing power
+    let mut power: usize = 0;
+    for (box_i, lenses) in boxes.iter().enumerate() {
+        for (lense_i, lense) in lenses.iter().enumerate() {
+            power += (1 + box_i) * (1 + lense_i) * (lense.1 as usize);
+        }
+    }
+    power
+}
+
+/// Apply the HASH alogrithm which performs 3 operations on each character and sums
+///
+pub fn hash(step: &[u8]) -> usize {
+    let mut sum = 0;
+    for &c in step {
+        sum += c as usize;
+        sum *= 17;
+        sum %= 256;
+    }
+
+    sum
+}
+
+/// Pack the label string (max 6 chars) into a single int for easier 
+/// storage and lookup
+///
+fn encode_label(label: &[u8]) -> u64 {
+    let mut x: u64 = 0;
+    for (i, &c) in label.iter().enumerate() {
+        x |= (c as u64) << (8 * i);
+    }
+
+    x
+}
+
+/// The official one is only available in unstable (as is remove!)
+///
+fn extract_if(list: &mut LinkedList<(u64, u8)>, label_id: u64) {
+    if let Some(index_to_remove) = list.iter().position(|&l| l.0 == label_id) {
+        let mut split_list = list.split_off(index_to_remove);
+        split_list.pop_front();
+        list.append(&mut split_list);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 1320);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 145);
+    }
+
+    #[test]
+    fn hashes_steps() {
+        assert_eq!(hash(b"HASH"), 52);
+        assert_eq!(hash(b"rn=1"), 30);
+        assert_eq!(hash(b"cm-"), 253);
+        assert_eq!(hash(b"rn"), 0);
+        assert_eq!(hash(b"qp"), 1);
+    }
+
+    #[test]
+    fn reports_missing_operation() {
+        let e = parse("rn=1,cm,qp=3").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 6, "cm"));
+    }
+}
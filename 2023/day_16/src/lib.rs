@@ -0,0 +1,213 @@
+use aoc_common::{Dir, Grid, ParseError, Pos, Solution};
+use std::fmt::Display;
+
+/// Mirrors, splitters and empty space
+///
+pub struct Contraption {
+    pub tiles: Grid<u8>,
+}
+
+/// A beam that has just entered the tile at pos heading in dir
+///
+#[derive(Clone, Copy)]
+struct Beam {
+    pos: Pos,
+    dir: Dir,
+}
+
+/// Advent of code - Day 16
+///
+/// Part 1 - Lasers bouncing and splitting around a grid
+/// Part 2 - Find optimal from all possible starting locs
+///
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input = Contraption;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+pub fn parse(data: &str) -> Result<Contraption, ParseError> {
+    Ok(Contraption {
+        tiles: Grid::parse_symbols(data, b"./\\|-")?,
+    })
+}
+
+/// Beam enters from the top left heading right
+///
+pub fn part1(contraption: &Contraption) -> usize {
+    let start = Beam {
+        pos: (0, 0),
+        dir: Dir::Right,
+    };
+    simulate(start, &contraption.tiles)
+}
+
+pub fn part2(contraption: &Contraption) -> usize {
+    simulate_max(&contraption.tiles)
+}
+
+/// Bounce and split lasers based on symbols and track the cells in the grid that
+/// they interact with. Then sum the number of cells
+///
+fn simulate(start: Beam, grid: &Grid<u8>) -> usize {
+    let mut beams: Vec<Beam> = Vec::new();
+    beams.push(start);
+
+    //Bit per direction for each cell that a beam has passed through
+    let mut energised = Grid::new(grid.width(), grid.height(), 0_u8);
+    let mut num_energised: usize = 0;
+
+    while let Some(b) = beams.pop() {
+        //A beam has already been here heading the same way so this one will follow the same path.
+        //This also stops beams getting stuck in a loop
+        let dir_bit = 1 << (b.dir as u8);
+        if energised[b.pos] & dir_bit != 0 {
+            continue;
+        }
+        if energised[b.pos] == 0 {
+            num_energised += 1;
+        }
+        energised[b.pos] |= dir_bit;
+
+        let (dir, split_dir) = match grid[b.pos] {
+            b'.' => (b.dir, None),                                //Just continue
+            b'\\' => (determine_bounce_backslash(b.dir), None),   //Reflect
+            b'/' => (determine_bounce_forwardslash(b.dir), None), //Reflect
+            b'-' => match determine_split_horizontal(b.dir) {
+                Some((d1, d2)) => (d1, Some(d2)),
+                None => (b.dir, None),
+            },
+            b'|' => match determine_split_vertical(b.dir) {
+                Some((d1, d2)) => (d1, Some(d2)),
+                None => (b.dir, None),
+            },
+            _ => unreachable!(), //Checked when parsing
+        };
+
+        //If the beam goes out of bounds we destroy it
+        for d in std::iter::once(dir).chain(split_dir) {
+            if let Some(pos) = grid.step(b.pos, d) {
+                beams.push(Beam { pos, dir: d });
+            }
+        }
+    }
+
+    num_energised
+}
+
+/// Simulate all possible starting places and find the most efficient
+/// Should really have done some memoisation to avoid repeating the same patterns but turns
+/// out brute force runs pretty quick
+///
+fn simulate_max(grid: &Grid<u8>) -> usize {
+    let (width, height) = (grid.width(), grid.height());
+    let mut beams: Vec<Beam> = Vec::with_capacity(2 * width + 2 * height);
+
+    //Note: We always start on the edge heading inwards
+    for x in 0..width {
+        beams.push(Beam {
+            pos: (x, 0),
+            dir: Dir::Down,
+        });
+
+        beams.push(Beam {
+            pos: (x, height - 1),
+            dir: Dir::Up,
+        });
+    }
+
+    for y in 0..height {
+        beams.push(Beam {
+            pos: (0, y),
+            dir: Dir::Right,
+        });
+
+        beams.push(Beam {
+            pos: (width - 1, y),
+            dir: Dir::Left,
+        });
+    }
+
+    //Find the max
+    beams.iter().map(|b| simulate(*b, grid)).max().unwrap()
+}
+
+/// If beam comes from the left we reflect down
+/// If beam comes from the right we reflect up
+/// If beam comes from down we reflect left
+/// If beam comes from up we reflect right
+///
+fn determine_bounce_backslash(dir: Dir) -> Dir {
+    match dir {
+        Dir::Right => Dir::Down,
+        Dir::Left => Dir::Up,
+        Dir::Up => Dir::Left,
+        Dir::Down => Dir::Right,
+    }
+}
+
+/// If beam comes from the left we reflect up
+/// If beam comes from the right we reflect down
+/// If beam comes from down we reflect right
+/// If beam comes from up we reflect left
+///
+fn determine_bounce_forwardslash(dir: Dir) -> Dir {
+    match dir {
+        Dir::Right => Dir::Up,
+        Dir::Left => Dir::Down,
+        Dir::Up => Dir::Right,
+        Dir::Down => Dir::Left,
+    }
+}
+
+/// If beam comes from left or right then do nothing
+/// If beam comes from top or bottom then split
+fn determine_split_horizontal(dir: Dir) -> Option<(Dir, Dir)> {
+    if dir.is_horizontal() {
+        return None;
+    }
+
+    Some((Dir::Left, Dir::Right))
+}
+
+/// If beam comes from left or right then split
+/// If beam comes from top or bottom then do nothing
+fn determine_split_vertical(dir: Dir) -> Option<(Dir, Dir)> {
+    if !dir.is_horizontal() {
+        return None;
+    }
+
+    Some((Dir::Up, Dir::Down))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 46);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), 51);
+    }
+}
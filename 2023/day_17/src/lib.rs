@@ -0,0 +1,177 @@
+use aoc_common::{Dir, Grid, ParseError, Pos, Solution};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// The heat loss of each block
+///
+pub struct City {
+    pub blocks: Grid<u8>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Node {
+    pos: Pos,
+    cost: usize,
+    steps_in_dir: u8,
+    from_dir: Option<Dir>,
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.pos.cmp(&other.pos))
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Advent of code - Day 17
+///
+/// Part 1 - Find the path with minimal heat loss - cannot go more than 3 in a straight line
+/// Part 2 - As above but cannot go more than 10 in a straight line and must go at least 4 in a straight line
+///
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input = City;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        part1(input).ok_or_else(|| "No path".to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        part2(input).ok_or_else(|| "No path".to_string())
+    }
+}
+
+/// Each block is a single digit heat loss
+///
+pub fn parse(data: &str) -> Result<City, ParseError> {
+    Ok(City {
+        blocks: Grid::parse_with(data, |b| b.is_ascii_digit().then(|| b - b'0'))?,
+    })
+}
+
+pub fn part1(city: &City) -> Option<usize> {
+    find_path(city, (1, 3))
+}
+
+pub fn part2(city: &City) -> Option<usize> {
+    find_path(city, (4, 10))
+}
+
+/// Dijkstra's shortest path to find the path with the lowest heat loss cost
+/// We cannot move more than 3 cells in the same direction
+/// We cannot move diagnonally
+/// None if no path keeps to the limits (e.g. the city is too small for the minimum run)
+///
+pub fn find_path(city: &City, minmax_steps_in_dir: (u8, u8)) -> Option<usize> {
+    let grid = &city.blocks;
+    let end = (grid.width() - 1, grid.height() - 1);
+
+    let mut open_queue = BinaryHeap::new();
+    let mut lowest_costs_to_node: HashMap<(Pos, Option<Dir>, u8), usize> = HashMap::new();
+
+    //Add the first node to the open set to be explored
+    open_queue.push(Node {
+        pos: (0, 0),
+        cost: 0,
+        steps_in_dir: 0,
+        from_dir: None,
+    });
+    lowest_costs_to_node.insert(((0, 0), None, 0), 0);
+
+    while let Some(curr_node) = open_queue.pop() {
+        //Reached the target? Without moving at all if the city is a single block
+        if curr_node.pos == end && (curr_node.steps_in_dir >= minmax_steps_in_dir.0 || curr_node.from_dir.is_none()) {
+            return Some(curr_node.cost);
+        }
+
+        // Check if we have already found a cheaper way
+        let key = (curr_node.pos, curr_node.from_dir, curr_node.steps_in_dir);
+        if curr_node.cost > *lowest_costs_to_node.get(&key).unwrap_or(&usize::MAX) {
+            continue;
+        }
+
+        //Find valid adjacent nodes that have a lower cost
+        for (s, next_pos) in grid.neighbours(curr_node.pos) {
+            //Don't backtrack
+            if Some(s.opposite()) == curr_node.from_dir {
+                continue;
+            }
+
+            //Check length limit in same dir
+            let next_steps_in_dir = if Some(s) == curr_node.from_dir {
+                curr_node.steps_in_dir + 1
+            } else {
+                1
+            };
+            if (curr_node.from_dir.is_some_and(|d| d != s) && curr_node.steps_in_dir < minmax_steps_in_dir.0)
+                || next_steps_in_dir > minmax_steps_in_dir.1
+            {
+                continue;
+            }
+
+            //Check to make sure there isn't already a cheaper route to this node
+            let next_cost = curr_node.cost + grid[next_pos] as usize;
+            let next_key = (next_pos, Some(s), next_steps_in_dir);
+            if next_cost < *lowest_costs_to_node.get(&next_key).unwrap_or(&usize::MAX) {
+                lowest_costs_to_node.insert(next_key, next_cost);
+                open_queue.push(Node {
+                    pos: next_pos,
+                    cost: next_cost,
+                    steps_in_dir: next_steps_in_dir,
+                    from_dir: Some(s),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    const EXAMPLE_LONG_RUN: &str = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Some(102));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Some(94));
+        assert_eq!(part2(&parse(EXAMPLE_LONG_RUN).unwrap()), Some(71));
+    }
+
+    #[test]
+    fn no_path() {
+        assert_eq!(part2(&parse("12\n34").unwrap()), None);
+        assert_eq!(part1(&parse("5").unwrap()), Some(0));
+        assert_eq!(Day17::part2(&parse("12\n34").unwrap()).err(), Some("No path".to_string()));
+    }
+}
@@ -0,0 +1,253 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use std::fmt::Display;
+
+/// Advent of code - Day 18
+///
+/// Part 1 - Dig trenches and find the area
+/// Part 2 - As part 1 but the colour is encoding the instructions
+///
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input = DigPlan;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        part1(input).ok_or_else(|| "Dig plan too large".to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        part2(input).ok_or_else(|| "Dig plan too large".to_string())
+    }
+}
+
+/// Both readings of the dig plan - the one we thought it was and the one hidden in the colours
+///
+pub struct DigPlan {
+    pub instructions_1: Vec<Instruction>,
+    pub instructions_2: Vec<Instruction>,
+}
+
+pub struct Instruction {
+    pub dir: u8,
+    pub steps: isize,
+}
+
+/// Both readings have to dig a loop that gets back to where it started
+///
+pub fn parse(data: &str) -> Result<DigPlan, ParseError> {
+    let plan = DigPlan {
+        instructions_1: parse_instructions_1(data.lines())?,
+        instructions_2: parse_instructions_2(data.lines())?,
+    };
+
+    if !returns_to_start(&plan.instructions_1) {
+        return Err(ParseError::at_end(data, "Dig plan doesn't return to the start"));
+    }
+    if !returns_to_start(&plan.instructions_2) {
+        return Err(ParseError::at_end(data, "Dig plan in the colours doesn't return to the start"));
+    }
+    Ok(plan)
+}
+
+/// None if the lagoon is too big to measure
+///
+pub fn part1(plan: &DigPlan) -> Option<usize> {
+    solve(&plan.instructions_1)
+}
+
+/// None if the lagoon is too big to measure
+///
+pub fn part2(plan: &DigPlan) -> Option<usize> {
+    solve(&plan.instructions_2)
+}
+
+/// R 6 (#70c710) =  Dir Steps (Colour)
+/// We don't need the colour - just ignore
+fn parse_instructions_1(lines: std::str::Lines) -> Result<Vec<Instruction>, ParseError> {
+    let mut ins = Vec::new();
+
+    for (i, l) in lines.enumerate() {
+        let mut fields = l.split(' ');
+
+        let dir = fields.next().unwrap_or_default();
+        if !matches!(dir, "R" | "L" | "D" | "U") {
+            return Err(ParseError::at(i + 1, l, dir, "Expected a direction R, L, D or U"));
+        }
+
+        let steps = fields
+            .next()
+            .ok_or_else(|| ParseError::new(i + 1, l.len() + 1, "", "Missing number of steps"))?;
+
+        ins.push(Instruction {
+            dir: dir.as_bytes()[0],
+            steps: parse_field::<u32>(i + 1, l, steps)? as isize,
+        });
+    }
+
+    Ok(ins)
+}
+
+/// The instructions are accidentally encoded in the colour
+/// #70c710 = R 461937
+/// The first 5 hex digits are the steps and the last is the direction
+///
+fn parse_instructions_2(lines: std::str::Lines) -> Result<Vec<Instruction>, ParseError> {
+    let mut ins = Vec::new();
+
+    for (i, l) in lines.enumerate() {
+        let colour = match l.split_once('#') {
+            Some((_, c)) => c.strip_suffix(')').unwrap_or(c),
+            None => return Err(ParseError::new(i + 1, l.len() + 1, "", "Missing colour")),
+        };
+
+        if colour.len() != 6 || !colour.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ParseError::at(i + 1, l, colour, "Expected a 6 digit hex colour"));
+        }
+
+        ins.push(Instruction {
+            dir: match colour.as_bytes()[5] {
+                b'0' => b'R',
+                b'1' => b'D',
+                b'2' => b'L',
+                b'3' => b'U',
+                _ => return Err(ParseError::at(i + 1, l, &colour[5..], "Expected a direction 0 to 3")),
+            },
+            steps: isize::from_str_radix(&colour[..5], 16).unwrap(), //Already checked it is hex
+        });
+    }
+
+    Ok(ins)
+}
+
+fn offset(dir: u8) -> (isize, isize) {
+    match dir {
+        b'R' => (1, 0),
+        b'L' => (-1, 0),
+        b'D' => (0, 1),
+        b'U' => (0, -1),
+        _ => unreachable!(),
+    }
+}
+
+/// The steps are at most 32 bits each so the totals can't overflow
+///
+fn returns_to_start(instructions: &[Instruction]) -> bool {
+    let (x, y) = instructions.iter().fold((0, 0), |(x, y), Instruction { dir, steps }| {
+        let (dx, dy) = offset(*dir);
+        (x + dx * steps, y + dy * steps)
+    });
+    (x, y) == (0, 0)
+}
+
+/// Run the instructions to generate the vertex positions and the number of cells inbetween
+///
+fn calc_verts(instructions: &[Instruction]) -> (Vec<(isize, isize)>, isize) {
+    let mut current_vertex = (0, 0);
+    let mut num_edge_cells = 0;
+    let mut vertices: Vec<(isize, isize)> = Vec::new();
+
+    for Instruction { dir, steps } in instructions {
+        let (x, y) = offset(*dir);
+        current_vertex = (current_vertex.0 + x * steps, current_vertex.1 + y * steps);
+        num_edge_cells += steps;
+        vertices.push(current_vertex);
+    }
+
+    (vertices, num_edge_cells)
+}
+
+/// Shoelace Formula: https://en.wikipedia.org/wiki/Shoelace_formula
+/// Calculate the area of a polygon from a number of points
+/// None if the area doesn't fit in an isize
+fn calc_shoelace_area(vertices: &[(isize, isize)]) -> Option<isize> {
+    let n = vertices.len();
+
+    let mut sum: isize = 0;
+    for (i, v) in vertices.iter().enumerate() {
+        let next = vertices[(i + 1) % n];
+        let cross = v.0.checked_mul(next.1)?.checked_sub(next.0.checked_mul(v.1)?)?;
+        sum = sum.checked_add(cross)?;
+    }
+
+    Some(sum.abs() / 2)
+}
+
+/// Pick's Theorem: https://en.wikipedia.org/wiki/Pick%27s_theorem
+/// i = A - b / 2 + 1
+/// i is the number of points interior to the polygon
+/// A is the polygon area
+/// b is the number of points in the boundary
+/// A plan that doubles back on itself can have less area than boundary so this can be negative
+fn calc_num_interior(a: isize, b: isize) -> Option<isize> {
+    a.checked_sub(b / 2)?.checked_add(1)
+}
+
+/// Calculate the total number of cells based on boundary and interior
+///
+pub fn solve(instructions: &[Instruction]) -> Option<usize> {
+    let (verts, num_edge_cells) = calc_verts(instructions);
+    let area = calc_shoelace_area(&verts)?;
+    let num_interior = calc_num_interior(area, num_edge_cells)?;
+    usize::try_from(num_interior.checked_add(num_edge_cells)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), Some(62));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), Some(952408144115));
+    }
+
+    #[test]
+    fn shoelace_area() {
+        assert_eq!(calc_shoelace_area(&[(0, 0), (6, 0), (6, 5), (0, 5)]), Some(30));
+        assert_eq!(calc_shoelace_area(&[(0, 5), (6, 5), (6, 0), (0, 0)]), Some(30));
+        assert_eq!(calc_shoelace_area(&[(0, 0), (4, 0), (4, 4)]), Some(8));
+        assert_eq!(calc_shoelace_area(&[(0, 0), (isize::MAX, 0), (isize::MAX, 2)]), None);
+    }
+
+    #[test]
+    fn interior_from_area_and_boundary() {
+        //A 6x5 rectangle has 22 boundary points and 5x4 inside
+        assert_eq!(calc_num_interior(30, 22), Some(20));
+    }
+
+    #[test]
+    fn plan_must_return_to_start() {
+        let e = parse("R 6 (#70c710)").err().unwrap();
+        assert_eq!((e.line, e.message.as_str()), (2, "Dig plan doesn't return to the start"));
+
+        let e = parse("R 6 (#000060)\nL 6 (#000061)").err().unwrap();
+        assert_eq!(e.message, "Dig plan in the colours doesn't return to the start");
+    }
+
+    #[test]
+    fn plan_that_doubles_back() {
+        //Just a trench there and back - 7 cells with nothing inside
+        let plan = parse("R 6 (#000060)\nL 6 (#000062)").unwrap();
+        assert_eq!(part1(&plan), Some(7));
+        assert_eq!(part2(&plan), Some(7));
+    }
+
+    #[test]
+    fn reports_bad_colour() {
+        let e = parse("R 6 (#70c710)\nD 5 (#0dc57)").err().unwrap();
+        assert_eq!(e.line, 2);
+    }
+}
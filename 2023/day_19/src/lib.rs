@@ -0,0 +1,321 @@
+use aoc_common::{blocks, parse_field, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Workflows keyed by their encoded name and the x,m,a,s ratings of each part
+///
+pub struct System {
+    pub workflows: HashMap<u32, Workflow>,
+    pub parts: Vec<Vec<u16>>,
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    conditionals: [Conditional; 4],
+    num_conditionals: usize,
+    else_dest: u32,
+}
+
+impl Workflow {
+    fn run(&self, vals: &[u16]) -> u32 {
+        for c in &self.conditionals[0..self.num_conditionals] {
+            let val = vals[c.var_idx];
+            if val > c.thresholds.0 && val < c.thresholds.1 {
+                return c.dest;
+            }
+        }
+
+        //Fallback
+        self.else_dest
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Conditional {
+    var_idx: usize,
+    thresholds: (u16, u16),
+    dest: u32,
+}
+
+/// Advent of code - Day 19
+///
+/// Part 1 - Workflows to accept or reject a part
+/// Part 2 - ???
+///
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input = System;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+/// Workflows and the parts are separated by a blank line
+///
+pub fn parse(data: &str) -> Result<System, ParseError> {
+    let (w, parts_offset, v) = match blocks(data)[..] {
+        [(_, w), (parts_offset, v)] => (w, parts_offset, v),
+        [_, _, (line, _), ..] => {
+            let msg = "Expected only one blank line, between the workflows and the parts";
+            return Err(ParseError::new(line, 1, "", msg));
+        }
+        _ => return Err(ParseError::at_end(data, "Expected a blank line between the workflows and the parts")),
+    };
+
+    let parts = v
+        .lines()
+        .enumerate()
+        .map(|(i, l)| parse_values(i + 1, l).map_err(|e| e.offset_lines(parts_offset)))
+        .collect::<Result<_, _>>()?;
+
+    Ok(System {
+        workflows: parse_workflows(w)?,
+        parts,
+    })
+}
+
+/// Run the workflows for each part and sum the ratings of those accepted
+///
+pub fn part1(system: &System) -> usize {
+    run(&system.parts, &system.workflows)
+}
+
+/// Not solved yet
+///
+pub fn part2(_system: &System) -> usize {
+    0
+}
+
+/// FORMAT: px{a<2006:qkq,m>2090:A,rfg}
+///
+/// Once all are parsed we check that every destination exists so that running can't get stuck
+///
+fn parse_workflows(block: &str) -> Result<HashMap<u32, Workflow>, ParseError> {
+    let mut map = HashMap::new();
+    let mut dests = Vec::new();
+
+    for (i, l) in block.lines().enumerate() {
+        let line_num = i + 1;
+
+        let (name, rules) = l
+            .split_once('{')
+            .ok_or_else(|| ParseError::at(line_num, l, l, "Expected '{' after the workflow name"))?;
+        let rules = rules
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::new(line_num, l.len() + 1, "", "Expected '}' at the end of the workflow"))?;
+        let id = parse_name(line_num, l, name)?;
+
+        let mut wf = Workflow {
+            conditionals: [Conditional {
+                var_idx: 0,
+                thresholds: (0, 0),
+                dest: 0,
+            }; 4],
+            num_conditionals: 0,
+            else_dest: 0,
+        };
+
+        //The last rule is the fallback with no condition
+        let mut rules: Vec<&str> = rules.split(',').collect();
+        let else_rule = rules.pop().unwrap_or_default();
+        if rules.len() > wf.conditionals.len() {
+            let msg = format!("More than {} conditions", wf.conditionals.len());
+            return Err(ParseError::at(line_num, l, rules[wf.conditionals.len()], msg));
+        }
+
+        for r in rules {
+            let (cond, dest) = parse_conditional(line_num, l, r)?;
+            wf.conditionals[wf.num_conditionals] = cond;
+            wf.num_conditionals += 1;
+            dests.push((line_num, l, dest));
+        }
+
+        wf.else_dest = parse_name(line_num, l, else_rule)?;
+        dests.push((line_num, l, else_rule));
+        map.insert(id, wf);
+    }
+
+    for (line_num, l, dest) in dests {
+        if !matches!(dest, "A" | "R") && !map.contains_key(&encode_name(dest.as_bytes())) {
+            return Err(ParseError::at(line_num, l, dest, "Unknown workflow"));
+        }
+    }
+    if !map.contains_key(&encode_name("in".as_bytes())) {
+        return Err(ParseError::at_end(block, "No 'in' workflow"));
+    }
+
+    Ok(map)
+}
+
+/// FORMAT: a<2006:qkq
+///
+fn parse_conditional<'a>(line_num: usize, l: &str, rule: &'a str) -> Result<(Conditional, &'a str), ParseError> {
+    let (test, dest) = rule
+        .split_once(':')
+        .ok_or_else(|| ParseError::at(line_num, l, rule, "Expected a condition and destination separated by ':'"))?;
+
+    let bytes = test.as_bytes();
+    let var_idx = bytes
+        .first()
+        .and_then(|&v| var_to_idx(v))
+        .ok_or_else(|| ParseError::at(line_num, l, test, "Expected a rating x, m, a or s"))?;
+
+    let val = parse_field::<u16>(line_num, l, test.get(2..).unwrap_or_default())?;
+    let thresholds = match bytes.get(1) {
+        Some(b'>') => (val, u16::MAX),
+        Some(b'<') => (0, val),
+        _ => return Err(ParseError::at(line_num, l, test, "Expected a '<' or '>' comparison")),
+    };
+
+    let cond = Conditional {
+        var_idx,
+        thresholds,
+        dest: parse_name(line_num, l, dest)?,
+    };
+    Ok((cond, dest))
+}
+
+/// FORMAT: {x=787,m=2655,a=1222,s=2876}
+///
+fn parse_values(line_num: usize, l: &str) -> Result<Vec<u16>, ParseError> {
+    let mut vals = vec![0; 4];
+
+    let groups = l
+        .strip_prefix('{')
+        .and_then(|g| g.strip_suffix('}'))
+        .ok_or_else(|| ParseError::at(line_num, l, l, "Expected the ratings to be inside {}"))?;
+
+    for group in groups.split(',') {
+        let (var, val) = group
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(line_num, l, group, "Expected rating=value"))?;
+        let var_idx = match var.as_bytes() {
+            &[v] => var_to_idx(v),
+            _ => None,
+        }
+        .ok_or_else(|| ParseError::at(line_num, l, var, "Expected a rating x, m, a or s"))?;
+
+        vals[var_idx] = parse_field(line_num, l, val)?;
+    }
+
+    Ok(vals)
+}
+
+/// Names are packed into a u32 so can be at most 4 letters
+///
+fn parse_name(line_num: usize, l: &str, name: &str) -> Result<u32, ParseError> {
+    if name.is_empty() || name.len() > 4 || !name.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return Err(ParseError::at(line_num, l, name, "Expected a workflow name of 1 to 4 letters"));
+    }
+    Ok(encode_name(name.as_bytes()))
+}
+
+/// Run the workflows for each line
+///
+fn run(parts: &[Vec<u16>], workflows: &HashMap<u32, Workflow>) -> usize {
+    let mut sum = 0;
+
+    let in_id = encode_name("in".as_bytes());
+    let accept_id = encode_name("A".as_bytes());
+    let reject_id = encode_name("R".as_bytes());
+
+    for values in parts {
+        let mut next_wf_id = in_id;
+        loop {
+            let wf = &workflows[&next_wf_id]; //Checked they all exist when parsing
+            next_wf_id = wf.run(values);
+
+            if next_wf_id == accept_id {
+                sum += values.iter().map(|&v| v as usize).sum::<usize>();
+                break;
+            }
+
+            if next_wf_id == reject_id {
+                break;
+            }
+        }
+    }
+
+    sum
+}
+
+/// It's easier to store ints that strings in rust so just pack the max 3 letter workflow name
+/// into a u32
+///
+fn encode_name(n: &[u8]) -> u32 {
+    let mut x = 0;
+
+    for (i, &b) in n.iter().enumerate() {
+        x |= (b as u32) << (8 * i);
+    }
+
+    x
+}
+
+/// Convert to an index so we can lookup easier
+///
+fn var_to_idx(var: u8) -> Option<usize> {
+    match var {
+        b'x' => Some(0),
+        b'm' => Some(1),
+        b'a' => Some(2),
+        b's' => Some(3),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 19114);
+    }
+
+    #[test]
+    fn reports_unknown_workflow() {
+        let e = parse("in{x>10:abc,A}\n\n{x=1,m=2,a=3,s=4}").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 9, "abc"));
+    }
+
+    #[test]
+    fn reports_line_in_parts() {
+        let e = parse("in{x>10:R,A}\n\n{x=1,m=2,a=3,s=4}\n{x=1,m=y,a=3,s=4}").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 8, "y"));
+    }
+
+    #[test]
+    fn accepts_crlf() {
+        assert_eq!(part1(&parse(&EXAMPLE.replace('\n', "\r\n")).unwrap()), 19114);
+
+        let e = parse("in{x>10:R,A}\r\n\r\n{x=1,m=2,a=3,s=4}\r\n{x=1,m=y,a=3,s=4}\r\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 8, "y"));
+    }
+
+    #[test]
+    fn needs_one_blank_line() {
+        let e = parse("in{x>10:R,A}\n{x=1,m=2,a=3,s=4}\n").err().unwrap();
+        assert_eq!(e.line, 3);
+        assert!(e.message.contains("Expected a blank line"), "{}", e);
+
+        let e = parse("in{x>10:R,A}\n\n{x=1,m=2,a=3,s=4}\n\n{x=1,m=2,a=3,s=4}").err().unwrap();
+        assert_eq!(e.line, 4);
+        assert!(e.message.contains("only one blank line"), "{}", e);
+    }
+}
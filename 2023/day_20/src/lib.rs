@@ -0,0 +1,311 @@
+use aoc_common::{ParseError, Solution};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+const MAX_NODES: usize = 64;
+
+/// The module graph and the indices of the broadcaster and rx (if there is one)
+///
+pub struct Network {
+    graph: Graph,
+    start_idx: usize,
+    rx_idx: Option<usize>,
+}
+
+#[derive(Clone)]
+struct Graph {
+    types: Vec<char>,
+    output_idx_map: Vec<Vec<u8>>,
+    input_idx_map: Vec<Vec<u8>>,
+    states: Vec<bool>,
+    pulses_from: Vec<u64>,
+}
+
+/// Advent of code - Day 20
+///
+/// Part 1 - Graph of pulses
+/// Part 2 - Part 2 find the lowest number of button presses that would trigger RX
+///
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input = Network;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        part2(input).ok_or_else(|| "No rx module".to_string())
+    }
+}
+
+/// FORMAT: %a -> inv, con
+///
+pub fn parse(data: &str) -> Result<Network, ParseError> {
+    let (graph, start_idx, rx_idx) = parse_graph(data)?;
+    Ok(Network {
+        graph,
+        start_idx,
+        rx_idx,
+    })
+}
+
+/// Count the number of low and high pulses in 1000 button presses and multiply together
+///
+pub fn part1(network: &Network) -> usize {
+    let mut graph = network.graph.clone();
+    let mut num_low = 0;
+    let mut num_high = 0;
+
+    for _ in 0..1000 {
+        let (low, high) = press_button(&mut graph, network.start_idx, |_, _, _| {});
+        num_low += low;
+        num_high += high;
+    }
+
+    num_low * num_high
+}
+
+/// Use LCM and cyclic nature to find the min number of runs for rx to be triggered.
+/// None if the network doesn't have an rx module (e.g. the examples)
+///
+pub fn part2(network: &Network) -> Option<usize> {
+    let mut graph = network.graph.clone();
+    let rx_idx = network.rx_idx?;
+
+    //Find all connections to single conjunction model connecting to RX.
+    //Find how many button presses it takes for each of them to send a high pulse which would then cause
+    //it to send a low pulse to RX. Take the LCM
+    let rx_feed_idx = graph.input_idx_map[rx_idx][0] as usize;
+    let rx_feed_inputs = graph.input_idx_map[rx_feed_idx].clone();
+    let mut button_presses = vec![0; rx_feed_inputs.len()];
+
+    let mut runs = 0;
+    loop {
+        press_button(&mut graph, network.start_idx, |f, t, p| {
+            //Are we sending a high pulse to the rx feeder?
+            for (i, x) in rx_feed_inputs.iter().enumerate() {
+                if p == 1 && t == rx_feed_idx && f == (*x as usize) {
+                    button_presses[i] = runs + 1;
+                }
+            }
+        });
+
+        if button_presses.iter().all(|&x| x > 0) {
+            return Some(button_presses.iter().fold(1, |acc, n| lcm(acc, *n)));
+        }
+
+        runs += 1;
+    }
+}
+
+/// Parse into a graph of adjacency lists
+///
+fn parse_graph(data: &str) -> Result<(Graph, usize, Option<usize>), ParseError> {
+    let mut graph = Graph {
+        types: vec!['0'; MAX_NODES],
+        output_idx_map: (0..MAX_NODES).map(|_| Vec::with_capacity(8)).collect(),
+        input_idx_map: (0..MAX_NODES).map(|_| Vec::with_capacity(8)).collect(),
+        states: vec![false; MAX_NODES],
+        pulses_from: vec![0; MAX_NODES],
+    };
+
+    let mut start_idx = None;
+    let mut rx_idx = None;
+    let mut idx_map = HashMap::new();
+    let mut next_idx = 0;
+
+    for (i, l) in data.lines().enumerate() {
+        let line_num = i + 1;
+        let (id, adjs_list) = l
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(line_num, l, l, "Expected 'module -> outputs'"))?;
+        let adjs = adjs_list.split(", ");
+
+        let module_type = match id.as_bytes().first() {
+            Some(t @ (b'b' | b'%' | b'&')) => *t as char,
+            _ => return Err(ParseError::at(line_num, l, id, "Expected a broadcaster, '%' flip-flop or '&' conjunction")),
+        };
+
+        let (idx, next) = get_or_insert(&id[1..], next_idx, &mut idx_map);
+        next_idx = check_num_nodes(next, line_num, l, id)?;
+
+        graph.types[idx as usize] = module_type;
+
+        for a in adjs {
+            let (a_idx, next) = get_or_insert(a, next_idx, &mut idx_map);
+            next_idx = check_num_nodes(next, line_num, l, a)?;
+            graph.output_idx_map[idx as usize].push(a_idx);
+            graph.input_idx_map[a_idx as usize].push(idx);
+
+            if a == "rx" {
+                rx_idx = Some(a_idx as usize);
+            }
+        }
+
+        if module_type == 'b' {
+            start_idx = Some(idx as usize);
+        }
+    }
+
+    let start_idx = start_idx.ok_or_else(|| ParseError::at_end(data, "No broadcaster module"))?;
+    Ok((graph, start_idx, rx_idx))
+}
+
+/// The graph is a fixed size so there is a limit on how many distinct modules there can be
+///
+fn check_num_nodes(num_nodes: u8, line_num: usize, l: &str, id: &str) -> Result<u8, ParseError> {
+    if num_nodes as usize > MAX_NODES {
+        let msg = format!("More than {} modules", MAX_NODES);
+        return Err(ParseError::at(line_num, l, id, msg));
+    }
+    Ok(num_nodes)
+}
+
+/// Starting at the start index (Broadcaster), press the button once and process pulses until
+/// none are left. Every pulse (from, to, pulse) is passed to the observer as it is received.
+/// Returns the number of low and high pulses sent
+///
+fn press_button(graph: &mut Graph, start_idx: usize, mut observer: impl FnMut(usize, usize, u8)) -> (usize, usize) {
+    let mut pulses_to_process = VecDeque::new();
+    let mut num_low = 0;
+    let mut num_high = 0;
+
+    pulses_to_process.push_back((usize::MAX, start_idx, 0));
+    num_low += 1;
+
+    while let Some((f, t, p)) = pulses_to_process.pop_front() {
+        observer(f, t, p);
+
+        let next_pulse = match graph.types[t] {
+            'b' => broadcast(),
+            '%' => flip_flop(t, p, graph),
+            '&' => conjunction(f, t, p, graph),
+            _ => None,
+        };
+
+        if let Some(np) = next_pulse {
+            for nt in &graph.output_idx_map[t] {
+                pulses_to_process.push_back((t, *nt as usize, np));
+                num_low += 1 - (np as usize);
+                num_high += np as usize;
+            }
+        }
+    }
+
+    (num_low, num_high)
+}
+
+/// Emits a low pulse to all connected nodes
+///
+fn broadcast() -> Option<u8> {
+    Some(0)
+}
+
+/// If it has received a low pulse it flips state and then if on sends a high pulse, otherwise sends a low
+///
+fn flip_flop(to_idx: usize, pulse: u8, graph: &mut Graph) -> Option<u8> {
+    if pulse == 0 {
+        graph.states[to_idx] = !graph.states[to_idx];
+
+        let p = if graph.states[to_idx] { 1 } else { 0 };
+        return Some(p);
+    }
+
+    None
+}
+
+/// Stores the pulses received from connected nodes - once it has high pulses from all inputs it sends
+/// a low - otherwise it sends a high
+///
+fn conjunction(from_idx: usize, to_idx: usize, pulse: u8, graph: &mut Graph) -> Option<u8> {
+    graph.pulses_from[to_idx] = if pulse == 1 {
+        graph.pulses_from[to_idx] | (1_u64) << from_idx
+    } else {
+        graph.pulses_from[to_idx] & !((1_u64) << from_idx)
+    };
+
+    let all_hi = graph.input_idx_map[to_idx]
+        .iter()
+        .all(|&x| graph.pulses_from[to_idx] & (1 << x) > 0);
+    let p = if all_hi { 0 } else { 1 };
+    Some(p)
+}
+
+/// Look up the index of a node by name, assigning the next free index if we haven't seen it before
+///
+fn get_or_insert(id: &str, next_idx: u8, idx_map: &mut HashMap<String, u8>) -> (u8, u8) {
+    match idx_map.get(id) {
+        Some(idx) => (*idx, next_idx),
+        None => {
+            let new_idx = next_idx;
+            idx_map.insert(id.to_string(), new_idx);
+            (new_idx, next_idx + 1)
+        }
+    }
+}
+
+/// Find the lowest common multiple of 2 numbers
+///
+fn lcm(a: usize, b: usize) -> usize {
+    let mut max = a.max(b);
+    let mut min = a.min(b);
+
+    loop {
+        let res = max % min;
+        if res == 0 {
+            return (a * b) / min;
+        }
+
+        max = min;
+        min = res;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    const EXAMPLE_WITH_STATE: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), 32000000);
+        assert_eq!(part1(&parse(EXAMPLE_WITH_STATE).unwrap()), 11687500);
+    }
+
+    #[test]
+    fn part2_needs_rx() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), None);
+    }
+
+    #[test]
+    fn lowest_common_multiple() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(6, 4), 12);
+        assert_eq!(lcm(3, 5), 15);
+        assert_eq!(lcm(7, 7), 7);
+        assert_eq!(lcm(3739, 3761), 3739 * 3761);
+    }
+
+    #[test]
+    fn reports_unknown_module_type() {
+        let e = parse("broadcaster -> a\n*a -> b").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+}
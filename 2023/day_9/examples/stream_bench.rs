@@ -0,0 +1,96 @@
+use aoc_common::Lcg;
+use std::io::BufReader;
+use std::time::Instant;
+
+/// Compares streaming extrapolation against parsing the whole input up front, on one thread or many
+///
+/// Usage: cargo run --release -p day_9 --example stream_bench -- [<input path> | --lines <n>] [--threads <n>]
+///
+/// With no path a report of random polynomial histories is generated in memory (200,000 lines by
+/// default). With a path the file is streamed from disk first and then read whole for comparison
+///
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut num_threads = day_9::default_threads();
+    if let Some(i) = args.iter().position(|a| a == "--threads") {
+        num_threads = args.get(i + 1).and_then(|n| n.parse().ok()).expect("--threads needs a number");
+        args.drain(i..i + 2);
+    }
+
+    let data = match args.first().map(|a| a.as_str()) {
+        Some("--lines") => {
+            let n = args.get(1).and_then(|n| n.parse().ok()).expect("--lines needs a number");
+            generate(n)
+        }
+        Some(path) => {
+            let now = Instant::now();
+            let file = std::fs::File::open(path).expect("Could not open input");
+            let summary = day_9::extrapolate_stream(BufReader::new(file)).expect("Could not stream input");
+            println!(
+                "stream from disk: {} lines, next {}, previous {}, took {:#?}",
+                summary.lines,
+                summary.next,
+                summary.previous,
+                now.elapsed()
+            );
+            std::fs::read_to_string(path).expect("Could not read input")
+        }
+        None => generate(200_000),
+    };
+    println!("{} MB", data.len() / 1_000_000);
+
+    let now = Instant::now();
+    let summary = day_9::extrapolate_stream(data.as_bytes()).expect("Could not stream input");
+    println!("stream:    next {}, previous {}, took {:#?}", summary.next, summary.previous, now.elapsed());
+
+    let now = Instant::now();
+    let report = day_9::parse(&data).expect("Could not parse input");
+    let (next, previous) = (day_9::part1(&report), day_9::part2(&report));
+    println!("table:     next {}, previous {}, took {:#?}", next, previous, now.elapsed());
+
+    let now = Instant::now();
+    let summary = day_9::extrapolate_parallel(&data, num_threads).expect("Could not parse input");
+    println!(
+        "parallel:  next {}, previous {}, took {:#?} with {} threads",
+        summary.next,
+        summary.previous,
+        now.elapsed(),
+        num_threads
+    );
+
+    //The original approach - a Vec per row of differences and a reversed copy for going backwards
+    let now = Instant::now();
+    let report = day_9::parse(&data).expect("Could not parse input");
+    let (mut next, mut previous) = (0i128, 0i128);
+    for nums in &report.histories {
+        let rev: Vec<i64> = nums.iter().rev().copied().collect();
+        next += day_9::extrapolate_recursively(nums).unwrap_or(0) as i128;
+        previous += day_9::extrapolate_recursively(&rev).unwrap_or(0) as i128;
+    }
+    println!("recursive: next {}, previous {}, took {:#?}", next, previous, now.elapsed());
+}
+
+/// Histories of 21 values from random polynomials of degree up to 5 with small coefficients
+///
+fn generate(num_lines: usize) -> String {
+    let mut lcg = Lcg::new(0x2023_0009);
+    let mut random = move |max: u64| lcg.below(max);
+
+    let mut data = String::with_capacity(num_lines * 120);
+    for _ in 0..num_lines {
+        let degree = random(6) as usize;
+        let coefficients: Vec<i64> = (0..=degree).map(|_| random(21) as i64 - 10).collect();
+
+        for n in 0..21i64 {
+            let value = coefficients.iter().rev().fold(0, |acc, &c| acc * n + c);
+            if n > 0 {
+                data.push(' ');
+            }
+            data += &value.to_string();
+        }
+        data.push('\n');
+    }
+
+    data
+}
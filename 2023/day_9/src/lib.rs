@@ -0,0 +1,471 @@
+use aoc_common::{parse_field, ParseError, Solution};
+use std::fmt::{self, Display};
+
+mod parallel;
+mod polynomial;
+mod stream;
+mod table;
+
+pub use parallel::{default_threads, extrapolate_parallel};
+pub use polynomial::{Polynomial, Rational};
+pub use stream::{extrapolate_in_place, extrapolate_stream, StreamError, StreamSummary};
+pub use table::{DifferenceTable, SequenceError};
+
+/// One history of values per line of the report
+///
+pub struct Report {
+    pub histories: Vec<Vec<i64>>,
+}
+
+/// A history that couldn't be extrapolated - lines are 1 based
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LineError {
+    pub line: usize,
+    pub error: SequenceError,
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+/// The sum of the extrapolated values of every history that could be extrapolated, and why the others couldn't
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Extrapolation {
+    pub sum: i128,
+    pub errors: Vec<LineError>,
+}
+
+/// The sum, followed by any lines that were skipped
+///
+impl Display for Extrapolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.sum)?;
+        if !self.errors.is_empty() {
+            let errors: Vec<String> = self.errors.iter().map(|e| e.to_string()).collect();
+            write!(f, " (skipped {})", errors.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Advent of code - Day 9
+///
+/// Part 1 - A triangle - find the differences between a sequence of numbers recursively until the difference is zero then extrapolate the next in sequence
+/// Part 2 - As part 1 but instead of extrapolating the next number we extrapolate the previous number
+///
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input = Report;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError> {
+        parse(data)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, String> {
+        Ok(part2(input))
+    }
+}
+
+/// FORMAT: 0 3 6 9 12 15
+///
+pub fn parse(data: &str) -> Result<Report, ParseError> {
+    let histories = data
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.split(' ')
+                .map(|unparsed| parse_field::<i64>(i + 1, line, unparsed))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Report { histories })
+}
+
+/// Given a series of numbers per line
+/// Find the differences between each number and then in turn the differences between those differences
+/// Do this until the differences are all zero.
+/// We then need to extrapolate the initial sequences by 1 successive element and sum
+///
+pub fn part1(report: &Report) -> Extrapolation {
+    sum_extrapolated(report, |t| t.next_value())
+}
+
+/// As part 1 but extrapolate one previous element
+///
+pub fn part2(report: &Report) -> Extrapolation {
+    sum_extrapolated(report, |t| t.previous_value())
+}
+
+/// Lines that can't be extrapolated (or would overflow the sum) are skipped and reported rather than
+/// failing the whole report
+///
+pub fn sum_extrapolated(report: &Report, f: impl Fn(&DifferenceTable) -> Result<i128, SequenceError>) -> Extrapolation {
+    let mut result = Extrapolation::default();
+
+    for (i, nums) in report.histories.iter().enumerate() {
+        let value = DifferenceTable::new(nums)
+            .and_then(|t| f(&t))
+            .and_then(|v| result.sum.checked_add(v).ok_or(SequenceError::Overflow));
+
+        match value {
+            Ok(sum) => result.sum = sum,
+            Err(error) => result.errors.push(LineError { line: i + 1, error }),
+        }
+    }
+
+    result
+}
+
+/// The generating polynomial of each history, in the same order as the report
+///
+pub fn polynomials(report: &Report) -> Vec<Result<Polynomial, SequenceError>> {
+    report.histories.iter().map(|nums| Polynomial::fit(nums)).collect()
+}
+
+/// Find the next value in the sequence by summing the last value of each row of differences
+///
+/// The simple version of DifferenceTable::next_value - it builds every row of differences again
+///
+pub fn extrapolate_recursively(nums: &[i64]) -> Result<i64, SequenceError> {
+    //Report the length of the whole sequence rather than the row of differences that ran out
+    extrapolate_diffs(nums).map_err(|e| match e {
+        SequenceError::NotPolynomial { .. } => SequenceError::NotPolynomial { terms: nums.len() },
+        e => e,
+    })
+}
+
+fn extrapolate_diffs(nums: &[i64]) -> Result<i64, SequenceError> {
+    if !nums.is_empty() && nums.iter().all(|&x| x == 0) {
+        return Ok(0);
+    }
+    if nums.len() < 2 {
+        return Err(SequenceError::NotPolynomial { terms: nums.len() });
+    }
+
+    let next_diff = extrapolate_diffs(generate_diffs(nums)?.as_slice())?;
+    next_diff.checked_add(nums[nums.len() - 1]).ok_or(SequenceError::Overflow)
+}
+
+fn generate_diffs(nums: &[i64]) -> Result<Vec<i64>, SequenceError> {
+    let slice = &nums[..nums.len() - 1];
+    slice
+        .iter()
+        .enumerate()
+        .map(|(i, x)| nums[i + 1].checked_sub(*x).ok_or(SequenceError::Overflow))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../test_input.txt");
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()).sum, 114);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()).sum, 2);
+    }
+
+    #[test]
+    fn extrapolates_each_history() {
+        assert_eq!(extrapolate_recursively(&[0, 3, 6, 9, 12, 15]), Ok(18));
+        assert_eq!(extrapolate_recursively(&[1, 3, 6, 10, 15, 21]), Ok(28));
+        assert_eq!(extrapolate_recursively(&[10, 13, 16, 21, 30, 45]), Ok(68));
+    }
+
+    #[test]
+    fn table_matches_recursion() {
+        for nums in parse(EXAMPLE).unwrap().histories {
+            let table = DifferenceTable::new(&nums).unwrap();
+            let rev: Vec<i64> = nums.iter().rev().copied().collect();
+            assert_eq!(table.next_value(), extrapolate_recursively(&nums).map(i128::from));
+            assert_eq!(table.previous_value(), extrapolate_recursively(&rev).map(i128::from));
+        }
+    }
+
+    #[test]
+    fn table_reproduces_the_sequence() {
+        let nums = [10, 13, 16, 21, 30, 45];
+        let table = DifferenceTable::new(&nums).unwrap();
+        assert_eq!(table.leading(), [10, 3, 0, 2]);
+        for (i, &n) in nums.iter().enumerate() {
+            assert_eq!(table.value_at(i as i64), Ok(n as i128));
+        }
+    }
+
+    #[test]
+    fn extrapolates_far_in_both_directions() {
+        //n^2 + n + 1 starting at n = 0
+        let table = DifferenceTable::new(&[1, 3, 7, 13, 21]).unwrap();
+        assert_eq!(table.value_at(1000), Ok(1001001));
+        assert_eq!(table.value_at(-50), Ok(2451));
+        assert_eq!(table.extrapolate(996), Ok(1001001));
+        assert_eq!(table.extrapolate(-50), Ok(2451));
+
+        //Linear
+        let table = DifferenceTable::new(&[0, 3, 6, 9, 12, 15]).unwrap();
+        assert_eq!(table.extrapolate(1000), Ok(15 + 3000));
+        assert_eq!(table.extrapolate(-1000), Ok(-3000));
+    }
+
+    #[test]
+    fn constant_sequences() {
+        assert_eq!(DifferenceTable::new(&[7, 7, 7]).unwrap().value_at(-12345), Ok(7));
+        assert_eq!(DifferenceTable::new(&[0]).unwrap().next_value(), Ok(0));
+    }
+
+    #[test]
+    fn detects_non_polynomial_sequences() {
+        //Powers of 2 never reach a row of zeros
+        let nums = [1, 2, 4, 8, 16, 32];
+        let not_polynomial = SequenceError::NotPolynomial { terms: 6 };
+        assert_eq!(DifferenceTable::new(&nums).err(), Some(not_polynomial));
+        assert_eq!(extrapolate_recursively(&nums).err(), Some(not_polynomial));
+
+        //Too short to tell
+        assert_eq!(DifferenceTable::new(&[5]), Err(SequenceError::NotPolynomial { terms: 1 }));
+        assert_eq!(DifferenceTable::new(&[]), Err(SequenceError::NotPolynomial { terms: 0 }));
+        assert_eq!(extrapolate_recursively(&[]), Err(SequenceError::NotPolynomial { terms: 0 }));
+    }
+
+    #[test]
+    fn detects_overflow() {
+        let nums = [i64::MIN, i64::MAX, i64::MIN];
+        assert_eq!(DifferenceTable::new(&nums), Err(SequenceError::Overflow));
+        assert_eq!(extrapolate_recursively(&nums), Err(SequenceError::Overflow));
+
+        //The next term is past i64::MAX
+        assert_eq!(extrapolate_recursively(&[0, i64::MAX / 2, i64::MAX / 2 * 2]), Err(SequenceError::Overflow));
+
+        //Fine as a table but a term that far out is too big even for i128
+        let table = DifferenceTable::new(&[0, 0, 10, 30]).unwrap();
+        assert_eq!(table.value_at(1000), Ok(4995000));
+        assert_eq!(table.value_at(i64::MAX), Err(SequenceError::Overflow));
+    }
+
+    #[test]
+    fn skips_bad_lines_and_carries_on() {
+        let report = parse("0 3 6 9 12 15\n1 2 4 8 16\n1 3 6 10 15 21\n9223372036854775807 -9223372036854775808 1").unwrap();
+        let result = part1(&report);
+        assert_eq!(result.sum, 18 + 28);
+        assert_eq!(
+            result.errors,
+            [
+                LineError {
+                    line: 2,
+                    error: SequenceError::NotPolynomial { terms: 5 }
+                },
+                LineError {
+                    line: 4,
+                    error: SequenceError::Overflow
+                }
+            ]
+        );
+        assert_eq!(result.to_string(), "46 (skipped line 2: Not polynomial within 5 terms, line 4: Arithmetic overflow)");
+    }
+
+    #[test]
+    fn stream_matches_parts() {
+        let summary = extrapolate_stream(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(summary.lines, 3);
+        assert_eq!(summary.next, part1(&parse(EXAMPLE).unwrap()));
+        assert_eq!(summary.previous, part2(&parse(EXAMPLE).unwrap()));
+    }
+
+    #[test]
+    fn stream_handles_crlf_and_bad_lines() {
+        let data = "0 3 6 9 12 15\r\n1 2 4 8 16\r\n1 3 6 10 15 21";
+        let summary = extrapolate_stream(data.as_bytes()).unwrap();
+        assert_eq!((summary.next.sum, summary.previous.sum), (18 + 28, -3));
+        assert_eq!(summary.next.errors.len(), 1);
+        assert_eq!(summary.previous.errors[0].line, 2);
+    }
+
+    #[test]
+    fn stream_stops_at_parse_errors() {
+        match extrapolate_stream("1 2 3\n4 x 6\n".as_bytes()) {
+            Err(StreamError::Parse(e)) => assert_eq!((e.line, e.column), (2, 3)),
+            r => panic!("Expected a parse error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn in_place_matches_table() {
+        for nums in parse(EXAMPLE).unwrap().histories {
+            let table = DifferenceTable::new(&nums).unwrap();
+            let mut row = nums.clone();
+            let expected = (table.next_value().unwrap(), table.previous_value().unwrap());
+            assert_eq!(extrapolate_in_place(&mut row), Ok(expected));
+        }
+
+        assert_eq!(extrapolate_in_place(&mut [1, 2, 4, 8]), Err(SequenceError::NotPolynomial { terms: 4 }));
+        assert_eq!(extrapolate_in_place(&mut []), Err(SequenceError::NotPolynomial { terms: 0 }));
+        assert_eq!(extrapolate_in_place(&mut [i64::MIN, i64::MAX]), Err(SequenceError::Overflow));
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let mut data = String::new();
+        for i in 0..50 {
+            data += &match i % 7 {
+                3 => format!("1 2 4 8 {}\n", 16 + i),
+                5 => "9223372036854775807 -9223372036854775808 1\n".to_string(),
+                _ => format!("{} {} {} {} {}\n", i, 2 * i, 4 * i + 1, 7 * i + 3, 11 * i + 6),
+            };
+        }
+        let report = parse(&data).unwrap();
+        let (next, previous) = (part1(&report), part2(&report));
+        assert_eq!(next.errors.len(), 14);
+
+        for threads in [0, 1, 2, 3, 7, 8, 50, 64] {
+            let summary = extrapolate_parallel(&data, threads).unwrap();
+            assert_eq!((summary.lines, &summary.next, &summary.previous), (50, &next, &previous), "{} threads", threads);
+        }
+    }
+
+    #[test]
+    fn parallel_example() {
+        let summary = extrapolate_parallel(EXAMPLE, default_threads()).unwrap();
+        assert_eq!((summary.next.sum, summary.previous.sum), (114, 2));
+        assert_eq!(extrapolate_parallel("", 4).unwrap().lines, 0);
+    }
+
+    /// A chunk of lines with the given values for both next and previous
+    ///
+    fn chunk(values: &[Result<i128, SequenceError>]) -> (StreamSummary, [stream::Running; 2]) {
+        let mut total = Extrapolation::default();
+        let mut running = stream::Running::default();
+        for (i, &v) in values.iter().enumerate() {
+            stream::add_tracked(&mut total, &mut running, i + 1, v);
+        }
+
+        let summary = StreamSummary {
+            lines: values.len(),
+            next: total.clone(),
+            previous: total,
+        };
+        (summary, [running; 2])
+    }
+
+    #[test]
+    fn parallel_totals_overflow_like_sequential() {
+        let big = i128::MAX - 5;
+
+        //Going through in order the 10 overflows, so the -10 is added and the total is big - 10
+        let sequential = chunk(&[Ok(big), Ok(10), Ok(-10)]).0;
+        assert_eq!(sequential.next.sum, big - 10);
+        assert_eq!(sequential.next.errors, vec![LineError { line: 2, error: SequenceError::Overflow }]);
+
+        //The second chunk alone adds up to 0 but it can't be added on to the first without changing what overflows
+        let chunks = vec![Ok(chunk(&[Ok(big)])), Ok(chunk(&[Ok(10), Ok(-10)]))];
+        assert_eq!(parallel::combine(chunks).unwrap(), None);
+
+        //When every total in between fits it's the same as adding them in order, errors and all
+        let not_polynomial = Err(SequenceError::NotPolynomial { terms: 3 });
+        let chunks = vec![Ok(chunk(&[Ok(big)])), Ok(chunk(&[Ok(-10), not_polynomial, Ok(5)]))];
+        let combined = parallel::combine(chunks).unwrap().unwrap();
+        let sequential = chunk(&[Ok(big), Ok(-10), not_polynomial, Ok(5)]).0;
+        assert_eq!(combined, sequential);
+
+        //A chunk that overflowed on its own can't be trusted either
+        let chunks = vec![Ok(chunk(&[Ok(-10)])), Ok(chunk(&[Ok(big), Ok(10)]))];
+        assert_eq!(parallel::combine(chunks).unwrap(), None);
+    }
+
+    #[test]
+    fn parallel_reports_the_first_parse_error() {
+        let data = "1 2 3\n".repeat(20) + "4 x 6\n" + &"1 2 3\n".repeat(20) + "y\n";
+        for threads in [1, 2, 5, 16] {
+            match extrapolate_parallel(&data, threads) {
+                Err(StreamError::Parse(e)) => assert_eq!((e.line, e.column), (21, 3), "{} threads", threads),
+                r => panic!("Expected a parse error, got {:?}", r),
+            }
+        }
+    }
+
+    #[test]
+    fn recovers_polynomials() {
+        let polys: Vec<String> = polynomials(&parse(EXAMPLE).unwrap())
+            .iter()
+            .map(|p| p.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(polys, ["3 n", "1/2 n^2 + 3/2 n + 1", "1/3 n^3 - n^2 + 11/3 n + 10"]);
+    }
+
+    #[test]
+    fn polynomial_evaluates_to_the_sequence() {
+        let nums = [10, 13, 16, 21, 30, 45];
+        let poly = Polynomial::fit(&nums).unwrap();
+        assert_eq!(poly.degree(), Some(3));
+        for (i, &n) in nums.iter().enumerate() {
+            assert_eq!(poly.evaluate(i as i64), Ok(Rational::integer(n as i128)));
+        }
+        assert_eq!(poly.evaluate(6).unwrap().to_integer(), Some(68));
+        assert_eq!(poly.evaluate(-1).unwrap().to_integer(), Some(5));
+    }
+
+    #[test]
+    fn high_degree_polynomial_overflows() {
+        //C(n, 34) - small values but the coefficients need 34! which doesn't fit in i128
+        let nums: Vec<i64> = (0..36).map(|n| [0, 1, 35][n.max(33) - 33]).collect();
+        assert_eq!(Polynomial::fit(&nums), Err(SequenceError::Overflow));
+        assert_eq!(DifferenceTable::new(&nums).unwrap().next_value(), Ok(630));
+
+        let poly = Polynomial::new(vec![Rational::integer(i128::MAX), Rational::ONE]);
+        assert_eq!(poly.evaluate(1), Err(SequenceError::Overflow));
+    }
+
+    #[test]
+    fn rational_arithmetic_is_checked() {
+        assert_eq!(Rational::new(1, 0), None);
+        assert_eq!(Rational::new(i128::MIN, -1), None);
+        assert_eq!(Rational::new(i128::MIN, i128::MIN), Some(Rational::ONE));
+        assert_eq!(Rational::new(0, i128::MIN), Some(Rational::ZERO));
+        assert_eq!(Rational::new(4, -6).map(|r| r.to_string()), Some("-2/3".to_string()));
+
+        let big = Rational::integer(i128::MAX);
+        assert_eq!(big.checked_add(Rational::ONE), None);
+        assert_eq!(big.checked_mul(Rational::integer(2)), None);
+        assert_eq!(Rational::integer(i128::MIN).checked_neg(), None);
+        assert_eq!(big.checked_neg().and_then(|r| r.checked_add(big)), Some(Rational::ZERO));
+    }
+
+    #[test]
+    fn prints_polynomials() {
+        let poly = Polynomial::new(vec![Rational::integer(4), Rational::integer(-1), Rational::new(3, 2).unwrap()]);
+        assert_eq!(poly.to_string(), "3/2 n^2 - n + 4");
+
+        let poly =
+            Polynomial::new(vec![Rational::ZERO, Rational::new(4, -6).unwrap(), Rational::integer(-1), Rational::ZERO]);
+        assert_eq!(poly.to_string(), "-n^2 - 2/3 n");
+        assert_eq!(poly.degree(), Some(2));
+
+        assert_eq!(Polynomial::fit(&[0, 0, 0]).unwrap().to_string(), "0");
+        assert_eq!(Polynomial::fit(&[0, 0, 0]).unwrap().degree(), None);
+        assert_eq!(Polynomial::fit(&[-5, -5]).unwrap().to_string(), "-5");
+    }
+
+    #[test]
+    fn reports_invalid_numbers() {
+        let e = parse("1 2 3\n4 x 6").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "x"));
+    }
+}
@@ -1,59 +1,46 @@
-/// Advent of code - Day 9
+use aoc_common::InputSource;
+use std::path::Path;
+use std::time::Instant;
+
+/// As every other day, unless --threads is given when the input is streamed through extrapolate_parallel
+/// on that many threads instead of being parsed up front. Only the input can be given with it
 ///
-/// Part 1 - A triangle - find the differences between a sequence of numbers recursively until the difference is zero then extrapolate the next in sequence
-/// Part 2 - As part 1 but instead of extrapolating the next number we extrapolate the previous number
+/// Usage: day_9 [<input path> | -] [--example] --threads <n>
 ///
 fn main() {
-    let now = std::time::Instant::now();
-    let input = std::fs::read_to_string("input.txt").unwrap();
-
-    let (result_1, result_2) = run(input.lines());
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(i) = args.iter().position(|a| a == "--threads") else {
+        aoc_common::day_main::<day_9::Day9>();
+        return;
+    };
+
+    let threads = args
+        .get(i + 1)
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .unwrap_or_else(|| fail("--threads must be a number greater than 0"));
+    args.drain(i..i + 2);
+
+    let source = match &args[..] {
+        [] => InputSource::Default,
+        [a] if a == "--example" => InputSource::Example,
+        [a] if !a.starts_with("--") => InputSource::from_arg(a),
+        _ => fail("Usage: [<input path> | -] [--example] --threads <n>"),
+    };
+    let data = source.read(Path::new(".")).unwrap_or_else(|e| fail(e));
+
+    let now = Instant::now();
+    let summary = day_9::extrapolate_parallel(&data, threads).unwrap_or_else(|e| fail(e));
     println!(
-        "Part 1: {}, Part 2: {}, took {:#?}",
-        result_1,
-        result_2,
-        now.elapsed()
+        "Part 1: {}, Part 2: {}, took {:#?} on {} threads",
+        summary.next,
+        summary.previous,
+        now.elapsed(),
+        threads
     );
 }
 
-/// Given a series of numbers per line
-/// Find the differences between each number and then in turn the differences between those differences
-/// Do this until the differences are all zero.
-/// We then need to extrapolate the initial sequences by 1 successive element and sum and then one previous element and sum
-///
-fn run(lines: std::str::Lines) -> (i32, i32) {
-    let mut sum_1: i32 = 0;
-    let mut sum_2: i32 = 0;
-
-    for line in lines {
-        let nums: Vec<i32> = line
-            .split(' ')
-            .map(|unparsed| unparsed.parse::<i32>().unwrap())
-            .collect();
-
-        //Guess is it is cheaper to reverse now that to parse all the numbers again
-        let nums_rev: Vec<i32> = nums.iter().rev().copied().collect();
-
-        sum_1 += extrapolate_recursively(nums.as_slice());
-        sum_2 += extrapolate_recursively(nums_rev.as_slice())
-    }
-
-    (sum_1, sum_2)
-}
-
-fn extrapolate_recursively(nums: &[i32]) -> i32 {
-    if nums.iter().all(|&x| x == 0) {
-        return 0;
-    }
-
-    return extrapolate_recursively(generate_diffs(nums).as_slice()) + nums[nums.len() - 1];
-}
-
-fn generate_diffs(nums: &[i32]) -> Vec<i32> {
-    let slice = &nums[..nums.len() - 1];
-    slice
-        .iter()
-        .enumerate()
-        .map(|(i, x)| nums[i + 1] - x)
-        .collect()
+fn fail(e: impl std::fmt::Display) -> ! {
+    eprintln!("{}", e);
+    std::process::exit(1);
 }
@@ -0,0 +1,104 @@
+use crate::stream::{extrapolate_stream, extrapolate_tracked, Running, StreamError, StreamSummary};
+use crate::Extrapolation;
+
+/// As extrapolate_stream but the input is split into one contiguous chunk of whole lines per thread
+/// which are parsed and extrapolated at the same time
+///
+/// Each chunk is totalled on its own and the chunks are then combined in line order, so the result
+/// (including the order of the errors and which parse error is reported) is the same whatever the
+/// number of threads
+///
+pub fn extrapolate_parallel(data: &str, num_threads: usize) -> Result<StreamSummary, StreamError> {
+    let chunks = split_lines(data, num_threads.max(1));
+
+    let chunk_results: Vec<ChunkResult> = std::thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .iter()
+            .map(|chunk| scope.spawn(move || extrapolate_tracked(chunk.as_bytes())))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("Extrapolation thread panicked"))
+            .collect()
+    });
+
+    //Which lines overflow the total depends on what came before them, so if any chunk can't just be added
+    //on then go through in order instead. The values are at most a few i64s each so this is very rare
+    match combine(chunk_results)? {
+        Some(summary) => Ok(summary),
+        None => extrapolate_stream(data.as_bytes()),
+    }
+}
+
+type ChunkResult = Result<(StreamSummary, [Running; 2]), StreamError>;
+
+/// The chunk summaries added up in line order, or None if that wouldn't give the same as going through
+/// every line in order
+///
+pub(crate) fn combine(chunk_results: Vec<ChunkResult>) -> Result<Option<StreamSummary>, StreamError> {
+    let mut summary = StreamSummary {
+        lines: 0,
+        next: Extrapolation::default(),
+        previous: Extrapolation::default(),
+    };
+
+    //Lines in each chunk are numbered from 1 so shift them by the lines in the chunks before
+    for result in chunk_results {
+        let (chunk, [next, previous]) = match result {
+            Ok(c) => c,
+            Err(StreamError::Parse(e)) => return Err(StreamError::Parse(e.offset_lines(summary.lines))),
+            Err(e) => return Err(e),
+        };
+
+        if !merge(&mut summary.next, chunk.next, next, summary.lines)
+            || !merge(&mut summary.previous, chunk.previous, previous, summary.lines)
+        {
+            return Ok(None);
+        }
+        summary.lines += chunk.lines;
+    }
+
+    Ok(Some(summary))
+}
+
+/// The number of threads to use if not told otherwise
+///
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Roughly equal sized chunks that each end just after a newline (or at the end of the data)
+///
+fn split_lines(data: &str, num_chunks: usize) -> Vec<&str> {
+    let target = data.len().div_ceil(num_chunks).max(1);
+    let mut chunks = Vec::with_capacity(num_chunks);
+    let mut rest = data;
+
+    while !rest.is_empty() {
+        let end = match rest.get(target.min(rest.len())..).and_then(|r| r.find('\n')) {
+            Some(i) => target + i + 1,
+            None => rest.len(),
+        };
+
+        let (chunk, remaining) = rest.split_at(end.min(rest.len()));
+        chunks.push(chunk);
+        rest = remaining;
+    }
+
+    chunks
+}
+
+/// Add the chunk on to the total, or false if adding its lines one by one would have skipped different ones
+///
+fn merge(total: &mut Extrapolation, chunk: Extrapolation, running: Running, line_offset: usize) -> bool {
+    total.sum = match total.sum.checked_add(chunk.sum) {
+        Some(sum) if running.fits_after(total.sum) => sum,
+        _ => return false,
+    };
+    total.errors.extend(chunk.errors.into_iter().map(|mut e| {
+        e.line += line_offset;
+        e
+    }));
+    true
+}
@@ -0,0 +1,221 @@
+use crate::table::{DifferenceTable, SequenceError};
+use std::fmt;
+
+/// An exact fraction, always stored in lowest terms with a positive denominator
+///
+/// Arithmetic is checked like the integer checked_ methods - None if the result doesn't fit in i128
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// None for a zero denominator or if the denominator can't be made positive
+    ///
+    pub fn new(num: i128, den: i128) -> Option<Rational> {
+        if den == 0 {
+            return None;
+        }
+
+        //The gcd is only 2^127 (which wraps to i128::MIN) when both are i128::MIN or one is zero,
+        //and dividing by i128::MIN still gives the right answer for those
+        let g = gcd(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let (num, den) = (num / g, den / g);
+        if den < 0 {
+            Some(Rational {
+                num: num.checked_neg()?,
+                den: den.checked_neg()?,
+            })
+        } else {
+            Some(Rational { num, den })
+        }
+    }
+
+    pub fn integer(n: i128) -> Rational {
+        Rational { num: n, den: 1 }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// The value if it is a whole number
+    ///
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+
+    pub fn checked_add(self, rhs: Rational) -> Option<Rational> {
+        let num = self.num.checked_mul(rhs.den)?.checked_add(rhs.num.checked_mul(self.den)?)?;
+        Rational::new(num, self.den.checked_mul(rhs.den)?)
+    }
+
+    pub fn checked_mul(self, rhs: Rational) -> Option<Rational> {
+        Rational::new(self.num.checked_mul(rhs.num)?, self.den.checked_mul(rhs.den)?)
+    }
+
+    pub fn checked_neg(self) -> Option<Rational> {
+        Some(Rational {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+}
+
+/// e.g. 3/2, -4 or 0
+///
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{}", self.num, den),
+        }
+    }
+}
+
+/// A polynomial in n where n = 0 is the first value of the sequence. Coefficients are indexed by power
+/// and there are no trailing zero coefficients, so the zero polynomial has none
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<Rational>,
+}
+
+impl Polynomial {
+    pub fn new(mut coefficients: Vec<Rational>) -> Polynomial {
+        while coefficients.last().is_some_and(|c| c.is_zero()) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// The lowest degree polynomial that passes through every value of the sequence
+    ///
+    pub fn fit(nums: &[i64]) -> Result<Polynomial, SequenceError> {
+        DifferenceTable::new(nums).and_then(|t| Polynomial::from_table(&t))
+    }
+
+    /// Newton's form is sum of d(j) * C(n, j) - expand each binomial n(n-1)...(n-j+1)/j! into powers of n
+    ///
+    /// The factorials and falling factorial coefficients outgrow i128 for high degrees (34! is past it)
+    /// even when the sequence itself is small, which is an Overflow error
+    ///
+    pub fn from_table(table: &DifferenceTable) -> Result<Polynomial, SequenceError> {
+        let overflow = SequenceError::Overflow;
+        let mut coefficients = vec![Rational::ZERO; table.leading().len()];
+
+        //The falling factorial n(n-1)...(n-j+1) as integer coefficients, built up one factor at a time
+        let mut falling: Vec<i128> = vec![1];
+        let mut factorial: i128 = 1;
+
+        for (j, &d) in table.leading().iter().enumerate() {
+            if j > 0 {
+                //Multiply by (n - (j - 1))
+                let shift = (j - 1) as i128;
+                let mut next = vec![0i128; falling.len() + 1];
+                for (p, &c) in falling.iter().enumerate() {
+                    next[p + 1] = next[p + 1].checked_add(c).ok_or(overflow)?;
+                    next[p] = next[p].checked_sub(c.checked_mul(shift).ok_or(overflow)?).ok_or(overflow)?;
+                }
+                falling = next;
+                factorial = factorial.checked_mul(j as i128).ok_or(overflow)?;
+            }
+
+            let scale = Rational::new(d as i128, factorial).ok_or(overflow)?;
+            for (p, &c) in falling.iter().enumerate() {
+                let term = scale.checked_mul(Rational::integer(c)).ok_or(overflow)?;
+                coefficients[p] = coefficients[p].checked_add(term).ok_or(overflow)?;
+            }
+        }
+
+        Ok(Polynomial::new(coefficients))
+    }
+
+    /// None for the zero polynomial
+    ///
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Indexed by power
+    ///
+    pub fn coefficients(&self) -> &[Rational] {
+        &self.coefficients
+    }
+
+    pub fn evaluate(&self, n: i64) -> Result<Rational, SequenceError> {
+        //Horner's method
+        let n = Rational::integer(n as i128);
+        self.coefficients.iter().rev().try_fold(Rational::ZERO, |acc, &c| {
+            acc.checked_mul(n)
+                .and_then(|x| x.checked_add(c))
+                .ok_or(SequenceError::Overflow)
+        })
+    }
+}
+
+/// e.g. 3/2 n^2 - n + 4
+///
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (power, &c) in self.coefficients.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+
+            let negative = c.num < 0;
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+
+            //A coefficient of 1 is left off unless it's the constant
+            let (num, den) = (c.num.unsigned_abs(), c.den);
+            if power == 0 || (num, den) != (1, 1) {
+                match den {
+                    1 => write!(f, "{}", num)?,
+                    den => write!(f, "{}/{}", num, den)?,
+                }
+                if power > 0 {
+                    write!(f, " ")?;
+                }
+            }
+
+            match power {
+                0 => {}
+                1 => write!(f, "n")?,
+                p => write!(f, "n^{}", p)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -0,0 +1,172 @@
+use crate::table::SequenceError;
+use crate::{Extrapolation, LineError};
+use aoc_common::{parse_field, ParseError};
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Totals for both directions of a whole stream
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamSummary {
+    pub lines: usize,
+    pub next: Extrapolation,
+    pub previous: Extrapolation,
+}
+
+/// Reading stops at the first line that can't be read or parsed. Lines that parse but can't be
+/// extrapolated are reported in the summary instead
+///
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "Could not read input: {}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> StreamError {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> StreamError {
+        StreamError::Parse(e)
+    }
+}
+
+/// Extrapolate every line of the stream forwards and backwards without holding the whole input
+///
+/// The line and number buffers are reused so memory only grows with the longest line (and the
+/// number of lines that can't be extrapolated) rather than the size of the input
+///
+pub fn extrapolate_stream(reader: impl BufRead) -> Result<StreamSummary, StreamError> {
+    extrapolate_tracked(reader).map(|(summary, _)| summary)
+}
+
+/// As extrapolate_stream but also how the running totals went for next and previous, so that the summary of
+/// part of the input can be checked before adding it on to the rest
+///
+pub(crate) fn extrapolate_tracked(mut reader: impl BufRead) -> Result<(StreamSummary, [Running; 2]), StreamError> {
+    let mut running = [Running::default(); 2];
+    let mut summary = StreamSummary {
+        lines: 0,
+        next: Extrapolation::default(),
+        previous: Extrapolation::default(),
+    };
+
+    let mut line = String::new();
+    let mut nums: Vec<i64> = Vec::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        summary.lines += 1;
+        let line_num = summary.lines;
+        let l = line.trim_end_matches(['\n', '\r']);
+
+        nums.clear();
+        for unparsed in l.split(' ') {
+            nums.push(parse_field(line_num, l, unparsed)?);
+        }
+
+        let result = extrapolate_in_place(&mut nums);
+        add_tracked(&mut summary.next, &mut running[0], line_num, result.map(|(next, _)| next));
+        add_tracked(&mut summary.previous, &mut running[1], line_num, result.map(|(_, previous)| previous));
+    }
+
+    Ok((summary, running))
+}
+
+/// The next and previous values of the sequence, overwriting it with the differences as it goes
+///
+/// The next value is the sum of the last value of each row of differences and the previous is the
+/// alternating sum of the first values
+///
+pub fn extrapolate_in_place(row: &mut [i64]) -> Result<(i128, i128), SequenceError> {
+    let terms = row.len();
+    let not_polynomial = SequenceError::NotPolynomial { terms };
+    if terms == 0 {
+        return Err(not_polynomial);
+    }
+
+    let mut next: i128 = 0;
+    let mut previous: i128 = 0;
+    let mut len = terms;
+
+    for depth in 0usize.. {
+        let current = &mut row[..len];
+        if current.iter().all(|&x| x == 0) {
+            break;
+        }
+        if len == 1 {
+            return Err(not_polynomial);
+        }
+
+        let first = current[0] as i128;
+        let signed_first = if depth.is_multiple_of(2) { first } else { -first };
+        next = next.checked_add(current[len - 1] as i128).ok_or(SequenceError::Overflow)?;
+        previous = previous.checked_add(signed_first).ok_or(SequenceError::Overflow)?;
+
+        for i in 0..len - 1 {
+            current[i] = current[i + 1].checked_sub(current[i]).ok_or(SequenceError::Overflow)?;
+        }
+        len -= 1;
+    }
+
+    Ok((next, previous))
+}
+
+/// The lowest and highest a running total got to, and whether adding a value to it ever overflowed
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Running {
+    pub min: i128,
+    pub max: i128,
+    pub overflowed: bool,
+}
+
+impl Running {
+    /// Whether adding the same values one by one on to `start` instead of zero would skip the same lines -
+    /// it would if none were skipped for overflowing and every total in between still fits
+    ///
+    pub(crate) fn fits_after(&self, start: i128) -> bool {
+        !self.overflowed && start.checked_add(self.min).is_some() && start.checked_add(self.max).is_some()
+    }
+}
+
+/// Add a line's value on to the total, or record why it couldn't be
+///
+pub(crate) fn add_tracked(
+    total: &mut Extrapolation,
+    running: &mut Running,
+    line: usize,
+    value: Result<i128, SequenceError>,
+) {
+    let error = match value.map(|v| total.sum.checked_add(v)) {
+        Ok(Some(sum)) => {
+            total.sum = sum;
+            running.min = running.min.min(sum);
+            running.max = running.max.max(sum);
+            return;
+        }
+        Ok(None) => {
+            running.overflowed = true;
+            SequenceError::Overflow
+        }
+        Err(error) => error,
+    };
+    total.errors.push(LineError { line, error });
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+
+/// Why a sequence can't be extrapolated
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SequenceError {
+    /// The differences never became all zero before running out of terms
+    NotPolynomial { terms: usize },
+    /// A difference or extrapolated value doesn't fit in the integer type
+    Overflow,
+}
+
+impl fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SequenceError::NotPolynomial { terms } => write!(f, "Not polynomial within {} terms", terms),
+            SequenceError::Overflow => write!(f, "Arithmetic overflow"),
+        }
+    }
+}
+
+impl std::error::Error for SequenceError {}
+
+/// The leading diagonal of the difference table - the first value of the sequence, the first
+/// difference, the first difference of the differences and so on until they are all zero.
+///
+/// This is all that is needed to find any term with Newton's forward difference formula
+/// a(k) = sum over j of C(k, j) * d(j) where d(j) is the first value of the jth row of differences.
+/// C(k, j) is the generalised binomial coefficient k(k-1)...(k-j+1)/j! which also works for negative k
+/// so we can go backwards too
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DifferenceTable {
+    leading: Vec<i64>,
+    len: usize,
+}
+
+impl DifferenceTable {
+    /// Builds the table once so that any number of terms can then be looked up
+    ///
+    /// A row of all zeros has to be reached while there are still terms left, otherwise there's
+    /// nothing to show the sequence is polynomial (a single value is a row on its own)
+    ///
+    pub fn new(nums: &[i64]) -> Result<DifferenceTable, SequenceError> {
+        let mut row = nums.to_vec();
+        let mut leading = Vec::with_capacity(row.len());
+
+        //Each row of differences is done in place - it's one shorter than the last
+        while !row.iter().all(|&x| x == 0) {
+            if row.len() == 1 {
+                return Err(SequenceError::NotPolynomial { terms: nums.len() });
+            }
+
+            leading.push(row[0]);
+            for i in 0..row.len() - 1 {
+                row[i] = row[i + 1].checked_sub(row[i]).ok_or(SequenceError::Overflow)?;
+            }
+            row.pop();
+        }
+
+        if nums.is_empty() {
+            return Err(SequenceError::NotPolynomial { terms: 0 });
+        }
+
+        Ok(DifferenceTable {
+            leading,
+            len: nums.len(),
+        })
+    }
+
+    /// Number of values in the original sequence
+    ///
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The first value of each row of differences - the first is the first term of the sequence
+    ///
+    pub fn leading(&self) -> &[i64] {
+        &self.leading
+    }
+
+    /// The term at the given index where 0 is the first value in the sequence. Indices past the
+    /// end extrapolate forwards and negative indices extrapolate backwards
+    ///
+    pub fn value_at(&self, index: i64) -> Result<i128, SequenceError> {
+        let k = index as i128;
+        let mut binomial: i128 = 1;
+        let mut sum: i128 = 0;
+
+        for (j, &d) in self.leading.iter().enumerate() {
+            if j > 0 {
+                //C(k, j) = C(k, j - 1) * (k - j + 1) / j and the division is always exact
+                binomial = binomial.checked_mul(k - j as i128 + 1).ok_or(SequenceError::Overflow)? / j as i128;
+            }
+            let term = binomial.checked_mul(d as i128).ok_or(SequenceError::Overflow)?;
+            sum = sum.checked_add(term).ok_or(SequenceError::Overflow)?;
+        }
+
+        Ok(sum)
+    }
+
+    /// The term `offset` places after the last one (or before the first for negative offsets)
+    ///
+    /// next_value() is the same as extrapolate(1) and previous_value() is extrapolate(-1)
+    ///
+    pub fn extrapolate(&self, offset: i64) -> Result<i128, SequenceError> {
+        if offset >= 0 {
+            let index = (self.len as i64 - 1).checked_add(offset).ok_or(SequenceError::Overflow)?;
+            self.value_at(index)
+        } else {
+            self.value_at(offset)
+        }
+    }
+
+    pub fn next_value(&self) -> Result<i128, SequenceError> {
+        self.extrapolate(1)
+    }
+
+    pub fn previous_value(&self) -> Result<i128, SequenceError> {
+        self.extrapolate(-1)
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const LINE_FORMAT: &str = "<year> <day> <part> <input checksum> <answer>";
+
+/// Which answer - the same day and part has a different answer for each input
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnswerKey {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub checksum: String,
+}
+
+/// The answers we know to be correct, kept in a plain text file so that changes show up in diffs
+///
+#[derive(Default)]
+pub struct KnownAnswers {
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+impl KnownAnswers {
+    /// A missing file is the same as an empty one
+    ///
+    pub fn load(path: &Path) -> Result<KnownAnswers, String> {
+        let data = match std::fs::read_to_string(path) {
+            Ok(d) => d,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(KnownAnswers::default()),
+            Err(e) => return Err(format!("Could not read answers from {}: {}", path.display(), e)),
+        };
+
+        let mut known = KnownAnswers::default();
+        for (i, l) in data.lines().enumerate() {
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+
+            let (key, answer) =
+                parse_line(l).ok_or_else(|| format!("{} line {}: Expected {}", path.display(), i + 1, LINE_FORMAT))?;
+            known.answers.insert(key, answer.to_string());
+        }
+
+        Ok(known)
+    }
+
+    /// Written sorted so that recording only ever adds lines in a predictable place
+    ///
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut data = format!(
+            "# Known answers - {}\n# Written by `aoc verify --record`. Delete a line to record a new answer for it\n",
+            LINE_FORMAT
+        );
+        for (k, answer) in &self.answers {
+            data += &format!("{} {} {} {} {}\n", k.year, k.day, k.part, k.checksum, answer);
+        }
+
+        std::fs::write(path, data).map_err(|e| format!("Could not write answers to {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, key: &AnswerKey) -> Option<&str> {
+        self.answers.get(key).map(|a| a.as_str())
+    }
+
+    pub fn insert(&mut self, key: AnswerKey, answer: String) {
+        self.answers.insert(key, answer);
+    }
+}
+
+/// FORMAT: 2023 9 1 a9f380d8352fee73 114
+///
+/// The answer is the rest of the line as not every answer is a number
+///
+fn parse_line(l: &str) -> Option<(AnswerKey, &str)> {
+    let mut fields = l.splitn(5, ' ');
+    let key = AnswerKey {
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        checksum: fields.next()?.to_string(),
+    };
+    let answer = fields.next().filter(|a| !a.is_empty())?;
+
+    Some((key, answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// A file of our own in the temp directory, removed when the test is done with it
+    ///
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> TempFile {
+            TempFile(std::env::temp_dir().join(format!("aoc-answers-{}-{}.txt", std::process::id(), name)))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn key(year: u16, day: u8, part: u8, checksum: &str) -> AnswerKey {
+        AnswerKey {
+            year,
+            day,
+            part,
+            checksum: checksum.to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let file = TempFile::new("round-trip");
+        std::fs::write(
+            &file.0,
+            "# A comment\n\n2023 10 2 0af566fccbe4afa0 1\n2023 9 1 a9f380d8352fee73 114\n2023 20 2 0123456789abcdef Answer with spaces\n",
+        )
+        .unwrap();
+
+        let known = KnownAnswers::load(&file.0).unwrap();
+        assert_eq!(known.get(&key(2023, 9, 1, "a9f380d8352fee73")), Some("114"));
+        assert_eq!(known.get(&key(2023, 20, 2, "0123456789abcdef")), Some("Answer with spaces"));
+        assert_eq!(known.get(&key(2023, 9, 2, "a9f380d8352fee73")), None);
+
+        //Saving sorts the lines, and what's saved loads back the same
+        known.save(&file.0).unwrap();
+        let saved = std::fs::read_to_string(&file.0).unwrap();
+        let lines: Vec<&str> = saved.lines().filter(|l| !l.starts_with('#')).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "2023 9 1 a9f380d8352fee73 114",
+                "2023 10 2 0af566fccbe4afa0 1",
+                "2023 20 2 0123456789abcdef Answer with spaces"
+            ]
+        );
+        assert_eq!(KnownAnswers::load(&file.0).unwrap().answers, known.answers);
+    }
+
+    #[test]
+    fn records_new_answers() {
+        let file = TempFile::new("record");
+        let mut known = KnownAnswers::load(&file.0).unwrap();
+        assert!(known.answers.is_empty());
+
+        known.insert(key(2022, 9, 1, "4a4c33cc22cf1b2e"), "13".to_string());
+        known.save(&file.0).unwrap();
+        assert_eq!(KnownAnswers::load(&file.0).unwrap().get(&key(2022, 9, 1, "4a4c33cc22cf1b2e")), Some("13"));
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let file = TempFile::new("malformed");
+        std::fs::write(&file.0, "# Header\n2023 9 1 a9f380d8352fee73 114\n2023 nine 1 a9f380d8352fee73 114\n").unwrap();
+        let e = KnownAnswers::load(&file.0).err().unwrap();
+        assert!(e.contains("line 3: Expected"), "{}", e);
+
+        assert!(parse_line("2023 9 1 a9f380d8352fee73").is_none());
+        assert!(parse_line("2023 9 1 a9f380d8352fee73 ").is_none());
+        assert!(parse_line("2023 9 300 a9f380d8352fee73 1").is_none());
+    }
+}
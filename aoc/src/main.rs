@@ -0,0 +1,361 @@
+use answers::{AnswerKey, KnownAnswers};
+use aoc_common::{
+    answer_text, bench_json, checksum, error_json, run_json, BenchConfig, Format, InputSource, Part, Solver, Timings,
+};
+use std::path::{Path, PathBuf};
+
+mod answers;
+mod solvers;
+
+const ANSWERS_FILE: &str = "answers.txt";
+
+const USAGE: &str = "Usage:
+    aoc run <year> <day> [--part <1|2>] [--input <path> | --example] [--format <text|json>]
+    aoc run <year> --all [--part <1|2>] [--example] [--format <text|json>]
+    aoc bench <year> (<day> | --all) [run options] [--iterations <n>] [--warmup <n>]
+    aoc verify [<year> [<day>]] [--example] [--record]
+
+    --input <path>     Read the input from the given file, or stdin if the path is -
+    --example          Read test_input.txt from the day's folder rather than input.txt
+    --format json      Print one JSON object per day with the answers, timings and input checksum
+    --iterations <n>   How many timed runs of each phase to take the stats from (default 100)
+    --warmup <n>       How many untimed runs of each phase to do first (default 10)
+    --record           Save answers that aren't in answers.txt yet rather than just reporting them";
+
+struct RunArgs {
+    year: u16,
+    day: Option<u8>,
+    part: Option<Part>,
+    source: InputSource,
+    format: Format,
+    /// Only when benchmarking
+    bench: Option<BenchConfig>,
+}
+
+/// Runs any of the Rust solutions from one place
+///
+/// Inputs are read from each day's folder by default so this can be run from anywhere in the repo
+///
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(|a| a.as_str()) {
+        Some("run") => parse_run_args(&args[1..], false).and_then(|a| run(&a)),
+        Some("bench") => parse_run_args(&args[1..], true).and_then(|a| run(&a)),
+        Some("verify") => parse_verify_args(&args[1..]).and_then(|a| verify(&a)),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+/// FORMAT: <year> (<day> | --all) [--part <1|2>] [--input <path> | --example] [--format <text|json>]
+///
+/// When benchmarking [--iterations <n>] [--warmup <n>] are also allowed
+///
+fn parse_run_args(args: &[String], bench: bool) -> Result<RunArgs, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut source = InputSource::Default;
+    let mut format = Format::Text;
+    let mut config = BenchConfig::default();
+
+    let mut it = args.iter();
+    while let Some(a) = it.next() {
+        match a.as_str() {
+            "--all" => all = true,
+            "--part" => {
+                let n = it.next().and_then(|p| p.parse::<u8>().ok());
+                part = Some(n.and_then(Part::from_number).ok_or("--part must be 1 or 2")?);
+            }
+            "--input" => {
+                let path = it.next().ok_or("--input needs a path")?;
+                source = set_source(source, InputSource::from_arg(path))?;
+            }
+            "--example" => source = set_source(source, InputSource::Example)?,
+            "--format" => {
+                let f = it.next().and_then(|f| Format::from_arg(f));
+                format = f.ok_or("--format must be text or json")?;
+            }
+            "--iterations" if bench => {
+                let n = it.next().and_then(|n| n.parse::<usize>().ok()).filter(|&n| n > 0);
+                config.iterations = n.ok_or("--iterations must be a number greater than 0")?;
+            }
+            "--warmup" if bench => {
+                let n = it.next().and_then(|n| n.parse::<usize>().ok());
+                config.warmup = n.ok_or("--warmup must be a number")?;
+            }
+            _ if year.is_none() => year = Some(a.parse::<u16>().map_err(|_| format!("Invalid year '{}'", a))?),
+            _ if day.is_none() => day = Some(a.parse::<u8>().map_err(|_| format!("Invalid day '{}'", a))?),
+            _ => return Err(format!("Unexpected argument '{}'\n{}", a, USAGE)),
+        }
+    }
+
+    let year = year.ok_or(USAGE)?;
+    if all == day.is_some() {
+        return Err(USAGE.to_string());
+    }
+    if all && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    Ok(RunArgs {
+        year,
+        day,
+        part,
+        source,
+        format,
+        bench: bench.then_some(config),
+    })
+}
+
+fn set_source(current: InputSource, next: InputSource) -> Result<InputSource, String> {
+    match current {
+        InputSource::Default => Ok(next),
+        _ => Err("Only one of --input or --example can be given".to_string()),
+    }
+}
+
+/// Run the selected days one after another and report the total time
+///
+fn run(args: &RunArgs) -> Result<(), String> {
+    let selected: Vec<Solver> = solvers::all()
+        .into_iter()
+        .filter(|s| s.year == args.year && args.day.is_none_or(|d| s.day == d))
+        .collect();
+
+    if selected.is_empty() {
+        return Err(match args.day {
+            Some(d) => format!("No solution for {} day {}", args.year, d),
+            None => format!("No solutions for {}", args.year),
+        });
+    }
+
+    let now = std::time::Instant::now();
+    let mut num_failed = 0;
+    for s in &selected {
+        let result = match args.bench {
+            Some(config) => bench_single(s, args, config),
+            None => run_single(s, args),
+        };
+        if let Err(e) = result {
+            match args.format {
+                Format::Text => eprintln!("{} Day {} - {}", s.year, s.day, e),
+                Format::Json => println!("{}", error_json(s.year, s.day, &e)),
+            }
+            num_failed += 1;
+        }
+    }
+
+    if selected.len() > 1 && args.format == Format::Text {
+        println!("Ran {} days, took {:#?}", selected.len(), now.elapsed());
+    }
+
+    match num_failed {
+        0 => Ok(()),
+        n => Err(format!("{} day(s) could not be run", n)),
+    }
+}
+
+fn run_single(solver: &Solver, args: &RunArgs) -> Result<(), String> {
+    let data = args.source.read(&day_dir(solver)).map_err(|e| e.to_string())?;
+
+    let (answers, timings) = solver.solve(&data, args.part).map_err(|e| e.to_string())?;
+
+    if args.format == Format::Json {
+        println!("{}", run_json(solver.year, solver.day, &checksum(&data), &answers, &timings));
+        return Ok(());
+    }
+
+    let mut line = format!("{} Day {} -", solver.year, solver.day);
+    if let Some(a) = &answers.part1 {
+        line += &format!(" Part 1: {},", answer_text(a));
+    }
+    if let Some(a) = &answers.part2 {
+        line += &format!(" Part 2: {},", answer_text(a));
+    }
+    println!("{} took {:#?} ({})", line, timings.total(), phase_times(&timings));
+
+    Ok(())
+}
+
+/// e.g. parse 1.2ms, part 1 300µs
+///
+fn phase_times(timings: &Timings) -> String {
+    let mut phases = vec![format!("parse {:#?}", timings.parse)];
+    if let Some(t) = timings.part1 {
+        phases.push(format!("part 1 {:#?}", t));
+    }
+    if let Some(t) = timings.part2 {
+        phases.push(format!("part 2 {:#?}", t));
+    }
+    phases.join(", ")
+}
+
+fn bench_single(solver: &Solver, args: &RunArgs, config: BenchConfig) -> Result<(), String> {
+    let data = args.source.read(&day_dir(solver)).map_err(|e| e.to_string())?;
+    let benchmark = solver.bench(&data, args.part, config).map_err(|e| e.to_string())?;
+
+    if args.format == Format::Json {
+        println!("{}", bench_json(solver.year, solver.day, &checksum(&data), &benchmark));
+        return Ok(());
+    }
+
+    println!(
+        "{} Day {} - {} iterations after {} warm-up",
+        solver.year, solver.day, config.iterations, config.warmup
+    );
+    println!("{}", benchmark);
+
+    Ok(())
+}
+
+struct VerifyArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    source: InputSource,
+    record: bool,
+}
+
+/// FORMAT: [<year> [<day>]] [--example] [--record]
+///
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut verify_args = VerifyArgs {
+        year: None,
+        day: None,
+        source: InputSource::Default,
+        record: false,
+    };
+
+    for a in args {
+        match a.as_str() {
+            "--example" => verify_args.source = InputSource::Example,
+            "--record" => verify_args.record = true,
+            _ if verify_args.year.is_none() => {
+                verify_args.year = Some(a.parse::<u16>().map_err(|_| format!("Invalid year '{}'", a))?)
+            }
+            _ if verify_args.day.is_none() => {
+                verify_args.day = Some(a.parse::<u8>().map_err(|_| format!("Invalid day '{}'", a))?)
+            }
+            _ => return Err(format!("Unexpected argument '{}'\n{}", a, USAGE)),
+        }
+    }
+
+    Ok(verify_args)
+}
+
+/// Run every selected day against its input and compare each answer to the one recorded for that input
+///
+/// Days without an input are skipped rather than failed as the inputs aren't checked in
+///
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let answers_path = repo_root().join(ANSWERS_FILE);
+    let mut known = KnownAnswers::load(&answers_path)?;
+
+    let selected: Vec<Solver> = solvers::all()
+        .into_iter()
+        .filter(|s| args.year.is_none_or(|y| s.year == y) && args.day.is_none_or(|d| s.day == d))
+        .collect();
+    if selected.is_empty() {
+        return Err("No solutions match".to_string());
+    }
+
+    let (mut num_passed, mut num_mismatched, mut num_failed, mut num_new, mut num_skipped) = (0, 0, 0, 0, 0);
+    for s in &selected {
+        let data = match args.source.read(&day_dir(s)) {
+            Ok(d) => d,
+            Err(e) if e.is_missing() => {
+                println!("{} Day {} - skipped, no input", s.year, s.day);
+                num_skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("{} Day {} - fail: {}", s.year, s.day, e);
+                num_failed += 1;
+                continue;
+            }
+        };
+
+        let answers = match s.solve(&data, None) {
+            Ok((answers, _)) => answers,
+            Err(e) => {
+                println!("{} Day {} - fail: {}", s.year, s.day, e);
+                num_failed += 1;
+                continue;
+            }
+        };
+
+        let checksum = checksum(&data);
+        for (part, answer) in [(1, answers.part1), (2, answers.part2)] {
+            let Some(answer) = answer else { continue };
+            let key = AnswerKey {
+                year: s.year,
+                day: s.day,
+                part,
+                checksum: checksum.clone(),
+            };
+
+            //A failed part is never recorded as the answer
+            let prefix = format!("{} Day {} Part {}", s.year, s.day, part);
+            let answer = match answer {
+                Ok(a) => a,
+                Err(e) => {
+                    println!("{} - fail: {}", prefix, e);
+                    num_failed += 1;
+                    continue;
+                }
+            };
+            match known.get(&key) {
+                Some(expected) if expected == answer => {
+                    println!("{} - pass", prefix);
+                    num_passed += 1;
+                }
+                Some(expected) => {
+                    println!("{} - mismatch: got {}, expected {}", prefix, answer, expected);
+                    num_mismatched += 1;
+                }
+                None if args.record => {
+                    println!("{} - recorded {}", prefix, answer);
+                    known.insert(key, answer);
+                    num_new += 1;
+                }
+                None => {
+                    println!("{} - no recorded answer, got {}", prefix, answer);
+                    num_new += 1;
+                }
+            }
+        }
+    }
+
+    if args.record && num_new > 0 {
+        known.save(&answers_path)?;
+    }
+
+    let new = if args.record { "recorded" } else { "unrecorded" };
+    println!(
+        "{} passed, {} mismatched, {} failed, {} {}, {} skipped",
+        num_passed, num_mismatched, num_failed, num_new, new, num_skipped
+    );
+
+    match num_mismatched + num_failed {
+        0 => Ok(()),
+        _ => Err("Verify failed".to_string()),
+    }
+}
+
+/// The parent of this crate
+///
+fn repo_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Day folders are relative to the repo root
+///
+fn day_dir(solver: &Solver) -> PathBuf {
+    repo_root().join(solver.dir)
+}
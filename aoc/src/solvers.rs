@@ -0,0 +1,21 @@
+use aoc_common::Solver;
+
+/// Every day that the runner knows about, in year then day order
+///
+pub fn all() -> Vec<Solver> {
+    vec![
+        Solver::of::<day_9_2022::Day9>("2022/Day9"),
+        Solver::of::<day_9::Day9>("2023/day_9"),
+        Solver::of::<day_10::Day10>("2023/day_10"),
+        Solver::of::<day_11::Day11>("2023/day_11"),
+        Solver::of::<day_12::Day12>("2023/day_12"),
+        Solver::of::<day_13::Day13>("2023/day_13"),
+        Solver::of::<day_14::Day14>("2023/day_14"),
+        Solver::of::<day_15::Day15>("2023/day_15"),
+        Solver::of::<day_16::Day16>("2023/day_16"),
+        Solver::of::<day_17::Day17>("2023/day_17"),
+        Solver::of::<day_18::Day18>("2023/day_18"),
+        Solver::of::<day_19::Day19>("2023/day_19"),
+        Solver::of::<day_20::Day20>("2023/day_20"),
+    ]
+}
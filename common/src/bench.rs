@@ -0,0 +1,174 @@
+use crate::error::ParseError;
+use crate::solution::{Part, Solution};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long each phase of a single run took. A part is None if it wasn't run
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+/// How many times to run each phase. The warm-up runs aren't included in the stats
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> BenchConfig {
+        BenchConfig {
+            warmup: 10,
+            iterations: 100,
+        }
+    }
+}
+
+/// Summary of the samples for one phase
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// None if there are no samples
+    ///
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        //Nearest rank - the smallest sample that at least 95% of the samples are no bigger than
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            p95,
+        })
+    }
+}
+
+/// Stats for each phase of a day. A part is None if it wasn't run
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Benchmark {
+    pub config: BenchConfig,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+/// Lines up the phases in a table with a column per stat
+///
+impl fmt::Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:<8}{:>12}{:>12}{:>12}", "", "min", "median", "p95")?;
+
+        let phases = [("parse", Some(self.parse)), ("part 1", self.part1), ("part 2", self.part2)];
+        for (name, stats) in phases {
+            if let Some(s) = stats {
+                let [min, median, p95] = [s.min, s.median, s.p95].map(|d| format!("{:.1?}", d));
+                writeln!(f, "{:<8}{:>12}{:>12}{:>12}", name, min, median, p95)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Time each phase separately. Parsing is timed on its own and then the parts are timed against one
+/// parsed input so a slow parse doesn't hide in the part timings
+///
+pub(crate) fn bench<S: Solution>(data: &str, part: Option<Part>, config: BenchConfig) -> Result<Benchmark, ParseError> {
+    let input = S::parse(data)?;
+
+    let parse = sample(config, || S::parse(black_box(data)).map(black_box));
+    let part1 = (part != Some(Part::Two)).then(|| sample(config, || black_box(S::part1(black_box(&input)))));
+    let part2 = (part != Some(Part::One)).then(|| sample(config, || black_box(S::part2(black_box(&input)))));
+
+    Ok(Benchmark {
+        config,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// Always takes at least one sample even if asked for none
+///
+fn sample<R>(config: BenchConfig, mut f: impl FnMut() -> R) -> Stats {
+    for _ in 0..config.warmup {
+        f();
+    }
+
+    let mut samples: Vec<Duration> = (0..config.iterations.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            f();
+            now.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&mut samples).unwrap_or_else(|| unreachable!())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: &[u64]) -> Vec<Duration> {
+        ms.iter().map(|&m| Duration::from_millis(m)).collect()
+    }
+
+    fn stats(ms: &[u64]) -> Option<(Duration, Duration, Duration)> {
+        Stats::from_samples(&mut millis(ms)).map(|s| (s.min, s.median, s.p95))
+    }
+
+    #[test]
+    fn odd_number_of_samples() {
+        let expected = millis(&[1, 3, 5]);
+        assert_eq!(stats(&[5, 1, 4, 2, 3]), Some((expected[0], expected[1], expected[2])));
+    }
+
+    #[test]
+    fn even_number_of_samples() {
+        //The median is half way between the middle two
+        let expected = millis(&[10, 25, 40]);
+        assert_eq!(stats(&[10, 40, 20, 30]), Some((expected[0], expected[1], expected[2])));
+
+        //95% of 20 is 19 so the 19th smallest is the p95
+        let samples: Vec<u64> = (1..=20).rev().collect();
+        let expected = (Duration::from_millis(1), Duration::from_micros(10500), Duration::from_millis(19));
+        assert_eq!(stats(&samples), Some(expected));
+    }
+
+    #[test]
+    fn single_sample() {
+        let one = Duration::from_millis(7);
+        assert_eq!(stats(&[7]), Some((one, one, one)));
+        assert_eq!(stats(&[]), None);
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed input - where it went wrong and what we found there
+///
+/// Lines and columns are 1 based and columns count bytes
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error for a piece of a line - the piece must be a slice of the line so we can work out the column
+    ///
+    pub fn at(line_num: usize, line: &str, piece: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(line_num, column_of(line, piece), piece, message)
+    }
+
+    /// Error for something that is missing from the input - reported just after the last line
+    ///
+    pub fn at_end(data: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(data.lines().count() + 1, 1, "", message)
+    }
+
+    /// For parsers that only see part of the input (e.g. one block of several) - shift the line
+    /// to be relative to the whole input
+    ///
+    pub fn offset_lines(mut self, num_lines: usize) -> ParseError {
+        self.line += num_lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Parse error at line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, " '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a piece of a line as a number (or anything else FromStr) reporting where it was if it isn't valid
+///
+pub fn parse_field<T: FromStr>(line_num: usize, line: &str, piece: &str) -> Result<T, ParseError> {
+    piece
+        .parse::<T>()
+        .map_err(|_| ParseError::at(line_num, line, piece, format!("Not a valid {}", std::any::type_name::<T>())))
+}
+
+/// 1 based column of the piece within the line. If the piece isn't part of the line then we fall back to column 1
+///
+pub fn column_of(line: &str, piece: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let piece_start = piece.as_ptr() as usize;
+    if piece_start >= start && piece_start <= start + line.len() {
+        piece_start - start + 1
+    } else {
+        1
+    }
+}
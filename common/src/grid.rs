@@ -0,0 +1,375 @@
+use crate::error::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// (x, y) with 0,0 at the top left
+///
+pub type Pos = (usize, usize);
+
+/// The 4 cardinal directions - Up is towards row 0
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+            Dir::Right => (1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+
+    pub fn turn_left(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Left => Dir::Down,
+            Dir::Down => Dir::Right,
+            Dir::Right => Dir::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        self.turn_left().opposite()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir::Left | Dir::Right)
+    }
+}
+
+/// A rectangular grid stored row by row so a row is a contiguous slice
+///
+/// Moving off the edge of the grid gives None rather than wrapping onto the next row
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Cells are given row by row
+    ///
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "Grid cells don't match the dimensions");
+        Grid { cells, width, height }
+    }
+
+    /// One row per line with each byte mapped to a cell. All rows must be the same length
+    /// and the mapping returns None for any symbol that isn't allowed
+    ///
+    pub fn parse_with(data: &str, mut f: impl FnMut(u8) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::with_capacity(data.len());
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in data.lines().enumerate() {
+            if y == 0 {
+                width = line.len();
+            }
+            if line.len() != width {
+                let msg = format!("Row is {} wide but the first row is {}", line.len(), width);
+                return Err(ParseError::new(y + 1, width.min(line.len()) + 1, line, msg));
+            }
+
+            for (x, b) in line.bytes().enumerate() {
+                match f(b) {
+                    Some(c) => cells.push(c),
+                    None => {
+                        let symbol = line.get(x..).and_then(|r| r.chars().next()).unwrap_or('?');
+                        return Err(ParseError::new(y + 1, x + 1, &symbol.to_string(), "Unexpected symbol"));
+                    }
+                }
+            }
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(ParseError::at_end(data, "Grid is empty"));
+        }
+
+        Ok(Grid { cells, width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn in_bounds(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if pos.0 < self.width && pos.1 < self.height {
+            Some(&self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if pos.0 < self.width && pos.1 < self.height {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position offset by (dx, dy) or None if that is off the grid
+    ///
+    pub fn offset(&self, pos: Pos, delta: (isize, isize)) -> Option<Pos> {
+        let x = pos.0 as isize + delta.0;
+        let y = pos.1 as isize + delta.1;
+        if self.in_bounds(x, y) {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// The adjacent position in the given direction or None if that is off the grid
+    ///
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        self.offset(pos, dir.offset())
+    }
+
+    /// All the in bounds positions adjacent (not diagonally) to the given one
+    ///
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Dir, Pos)> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d).map(|p| (d, p)))
+    }
+
+    /// Every position row by row
+    ///
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The first position (row by row) whose cell matches
+    ///
+    pub fn find(&self, pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|i| (i % self.width, i / self.width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Rows become columns
+    ///
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// The top row becomes the right hand column
+    ///
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// The top row becomes the left hand column
+    ///
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Build a new grid where each new (x, y) takes the cell from the source position given by the mapping
+    ///
+    fn remap(&self, width: usize, height: usize, src: impl Fn(usize, usize) -> Pos) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[src(x, y)].clone());
+            }
+        }
+
+        Grid { cells, width, height }
+    }
+}
+
+impl Grid<u8> {
+    /// One byte per cell, one row per line
+    ///
+    pub fn parse(data: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_with(data, Some)
+    }
+
+    /// As parse but only the given symbols are allowed
+    ///
+    pub fn parse_symbols(data: &str, symbols: &[u8]) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_with(data, |b| symbols.contains(&b).then_some(b))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(pos.0 < self.width && pos.1 < self.height, "{:?} is out of bounds", pos);
+        &self.cells[pos.1 * self.width + pos.0]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(pos.0 < self.width && pos.1 < self.height, "{:?} is out of bounds", pos);
+        &mut self.cells[pos.1 * self.width + pos.0]
+    }
+}
+
+/// Prints the grid back out as text - handy for debugging
+///
+impl fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //3 wide and 2 high so mixing up width and height shows
+    const ABC: &str = "abc\ndef";
+
+    fn grid(data: &str) -> Grid<u8> {
+        Grid::parse(data).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_in_order() {
+        let g = grid(ABC);
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[(2, 0)], b'c');
+        assert_eq!(g[(0, 1)], b'd');
+        assert_eq!(g.get((3, 0)), None);
+        assert_eq!(g, Grid::from_vec(3, 2, b"abcdef".to_vec()));
+        assert_eq!(g.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn reports_uneven_rows() {
+        let e = Grid::parse("abc\nde").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.message, "Row is 2 wide but the first row is 3");
+
+        let e = Grid::parse("abc\ndefg").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 4));
+    }
+
+    #[test]
+    fn reports_unexpected_symbol() {
+        let e = Grid::parse_symbols("abc\nd!f", b"abcdef").err().unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "!"));
+        assert_eq!(e.message, "Unexpected symbol");
+
+        let e = Grid::parse("").err().unwrap();
+        assert_eq!(e.message, "Grid is empty");
+    }
+
+    #[test]
+    fn columns_and_map() {
+        let g = grid(ABC);
+        assert_eq!(g.column(1).copied().collect::<Vec<u8>>(), b"be");
+        assert_eq!(g.column(2).copied().collect::<Vec<u8>>(), b"cf");
+        assert_eq!(g.map(|&c| c.to_ascii_uppercase()), grid("ABC\nDEF"));
+        assert_eq!(g.find(|&c| c == b'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let g = grid(ABC);
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(g.rotate_clockwise(), grid("da\neb\nfc"));
+        assert_eq!(g.rotate_anticlockwise(), grid("cf\nbe\nad"));
+
+        assert_eq!(g.transpose().transpose(), g);
+        assert_eq!(g.rotate_clockwise().rotate_anticlockwise(), g);
+        let four_turns = (0..4).fold(g.clone(), |r, _| r.rotate_clockwise());
+        assert_eq!(four_turns, g);
+    }
+
+    #[test]
+    fn steps_stay_on_the_grid() {
+        let g = grid(ABC);
+        assert_eq!(g.step((0, 0), Dir::Left), None);
+        assert_eq!(g.step((2, 1), Dir::Down), None);
+        assert_eq!(g.step((2, 0), Dir::Down), Some((2, 1)));
+        assert_eq!(g.neighbours((0, 1)).map(|(_, p)| p).collect::<Vec<Pos>>(), vec![(0, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Left.turn_right(), Dir::Up);
+        for d in Dir::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!((0..4).fold(d, |d, _| d.turn_left()), d);
+            assert_eq!(d.turn_left().turn_left(), d.opposite());
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const INPUT_FILE: &str = "input.txt";
+const EXAMPLE_FILE: &str = "test_input.txt";
+
+/// Where to read the puzzle input from
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// input.txt in the day's folder
+    #[default]
+    Default,
+    /// test_input.txt in the day's folder
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// "-" is stdin, anything else is a path
+    ///
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Read the whole input. The default and example files are relative to the given folder
+    ///
+    pub fn read(&self, dir: &Path) -> Result<String, InputError> {
+        let path = match self {
+            InputSource::Default => dir.join(INPUT_FILE),
+            InputSource::Example => dir.join(EXAMPLE_FILE),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                let mut data = String::new();
+                return match std::io::stdin().read_to_string(&mut data) {
+                    Ok(_) => Ok(data),
+                    Err(e) => Err(InputError {
+                        from: self.clone(),
+                        path: None,
+                        error: e,
+                    }),
+                };
+            }
+        };
+
+        std::fs::read_to_string(&path).map_err(|e| InputError {
+            from: self.clone(),
+            path: Some(path),
+            error: e,
+        })
+    }
+}
+
+/// Failed to read the input - keeps hold of where we were looking so we can say so
+///
+#[derive(Debug)]
+pub struct InputError {
+    from: InputSource,
+    path: Option<PathBuf>,
+    error: std::io::Error,
+}
+
+impl InputError {
+    /// The file doesn't exist, as opposed to existing but not being readable
+    ///
+    pub fn is_missing(&self) -> bool {
+        self.path.is_some() && self.error.kind() == std::io::ErrorKind::NotFound
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = match &self.path {
+            Some(p) => p.display().to_string(),
+            None => return write!(f, "Could not read input from stdin: {}", self.error),
+        };
+
+        if !self.is_missing() {
+            return write!(f, "Could not read input file {}: {}", path, self.error);
+        }
+
+        match self.from {
+            InputSource::Default => write!(f, "No puzzle input at {} - save your input there or pass a path", path),
+            InputSource::Example => write!(f, "No example input at {}", path),
+            _ => write!(f, "Input file {} does not exist", path),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Stable fingerprint of an input so results can be matched to the input they came from
+///
+/// 64 bit FNV-1a over the lines so line endings and a missing final newline don't change it
+///
+pub fn checksum(data: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for line in data.lines() {
+        for b in line.bytes().chain(std::iter::once(b'\n')) {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// The blocks of lines separated by blank lines, each with the number of lines before it so that parse errors
+/// can be offset to the whole input. Lines can end in \n or \r\n
+///
+pub fn blocks(data: &str) -> Vec<(usize, &str)> {
+    let mut blocks = Vec::new();
+    let (mut start, mut start_line, mut pos) = (0, 0, 0);
+
+    for (i, line) in data.split_inclusive('\n').enumerate() {
+        if line.trim_end_matches(['\n', '\r']).is_empty() {
+            blocks.push((start_line, &data[start..pos]));
+            start = pos + line.len();
+            start_line = i + 1;
+        }
+        pos += line.len();
+    }
+    blocks.push((start_line, &data[start..]));
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_with_either_line_ending() {
+        assert_eq!(blocks("ab\ncd\n\nef\n"), vec![(0, "ab\ncd\n"), (3, "ef\n")]);
+        assert_eq!(blocks("ab\r\ncd\r\n\r\nef\r\n"), vec![(0, "ab\r\ncd\r\n"), (3, "ef\r\n")]);
+        assert_eq!(blocks("ab"), vec![(0, "ab")]);
+        assert_eq!(blocks("ab\n\n\ncd"), vec![(0, "ab\n"), (2, ""), (3, "cd")]);
+        assert_eq!(checksum("ab\r\ncd\r\n"), checksum("ab\ncd"));
+    }
+}
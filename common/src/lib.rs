@@ -0,0 +1,20 @@
+//! Shared plumbing for the Rust Advent of Code solutions
+//!
+//! Each day implements `Solution` so that it can be run standalone from its own crate
+//! or dispatched to from the `aoc` runner
+//!
+mod bench;
+mod error;
+mod grid;
+mod input;
+mod random;
+mod report;
+mod solution;
+
+pub use bench::{BenchConfig, Benchmark, Stats, Timings};
+pub use error::{column_of, parse_field, ParseError};
+pub use grid::{Dir, Grid, Pos};
+pub use input::{blocks, checksum, InputError, InputSource};
+pub use random::Lcg;
+pub use report::{bench_json, error_json, escape, run_json, Format, JsonObject};
+pub use solution::{answer_text, day_main, Answers, Part, Solution, Solver};
@@ -0,0 +1,37 @@
+/// A seeded linear congruential generator for test and benchmark data - deterministic so runs are comparable,
+/// and nowhere near good enough for anything else
+///
+#[derive(Clone, Debug)]
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Lcg {
+        Lcg { state: seed }
+    }
+
+    /// The next number in 0..max
+    ///
+    pub fn below(&mut self, max: u64) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        //The low bits of an LCG repeat quickly so only use the top ones
+        (self.state >> 33) % max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Lcg::new(7);
+        let mut b = Lcg::new(7);
+        let mut other = Lcg::new(8);
+        let first: Vec<u64> = (0..100).map(|_| a.below(10)).collect();
+        assert!(first.iter().all(|&n| n < 10));
+        assert_eq!(first, (0..100).map(|_| b.below(10)).collect::<Vec<_>>());
+        assert_ne!(first, (0..100).map(|_| other.below(10)).collect::<Vec<_>>());
+    }
+}
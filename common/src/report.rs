@@ -0,0 +1,155 @@
+use crate::bench::{Benchmark, Stats, Timings};
+use crate::solution::Answers;
+use std::fmt;
+
+/// How results are printed
+///
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// One human readable line per day
+    #[default]
+    Text,
+    /// One JSON object per day per line (JSON Lines) so the output of an --all run can be streamed
+    Json,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Option<Format> {
+        match arg {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Just enough JSON writing for the reports - an object of fields whose values are already encoded
+///
+#[derive(Clone, Debug, Default)]
+pub struct JsonObject {
+    fields: Vec<(String, String)>,
+}
+
+impl JsonObject {
+    pub fn new() -> JsonObject {
+        JsonObject::default()
+    }
+
+    pub fn string(self, key: &str, value: &str) -> JsonObject {
+        self.raw(key, escape(value))
+    }
+
+    pub fn number(self, key: &str, value: impl Into<u128>) -> JsonObject {
+        self.raw(key, value.into().to_string())
+    }
+
+    pub fn object(self, key: &str, value: JsonObject) -> JsonObject {
+        self.raw(key, value.to_string())
+    }
+
+    /// Missing values are written as null rather than leaving the key out so every report has the same shape
+    ///
+    pub fn optional<T>(self, key: &str, value: Option<T>, f: impl FnOnce(T) -> String) -> JsonObject {
+        self.raw(key, value.map_or_else(|| "null".to_string(), f))
+    }
+
+    fn raw(mut self, key: &str, encoded: String) -> JsonObject {
+        self.fields.push((escape(key), encoded));
+        self
+    }
+}
+
+impl fmt::Display for JsonObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", key, value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Quoted JSON string
+///
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// FORMAT: {"year":2023,"day":9,"input_checksum":"..","part1":"114","part2":"2","part1_error":null,"part2_error":null,"timings_ns":{"parse":..,"part1":..,"part2":..}}
+///
+/// Answers are always strings as not every answer is a number. Parts that weren't run are null, and parts
+/// that failed have a null answer and the reason in the error
+///
+pub fn run_json(year: u16, day: u8, checksum: &str, answers: &Answers, timings: &Timings) -> JsonObject {
+    let timings_ns = JsonObject::new()
+        .number("parse", timings.parse.as_nanos())
+        .optional("part1", timings.part1, |t| t.as_nanos().to_string())
+        .optional("part2", timings.part2, |t| t.as_nanos().to_string())
+        .number("total", timings.total().as_nanos());
+
+    JsonObject::new()
+        .number("year", year)
+        .number("day", day)
+        .string("input_checksum", checksum)
+        .optional("part1", answer(&answers.part1), escape)
+        .optional("part2", answer(&answers.part2), escape)
+        .optional("part1_error", error(&answers.part1), escape)
+        .optional("part2_error", error(&answers.part2), escape)
+        .object("timings_ns", timings_ns)
+}
+
+fn answer(part: &Option<Result<String, String>>) -> Option<&str> {
+    part.as_ref()?.as_deref().ok()
+}
+
+fn error(part: &Option<Result<String, String>>) -> Option<&str> {
+    part.as_ref()?.as_ref().err().map(String::as_str)
+}
+
+/// FORMAT: {"year":2023,"day":9,"input_checksum":"..","iterations":100,"warmup":10,"parse":{"min_ns":..,"median_ns":..,"p95_ns":..},"part1":{..},"part2":{..}}
+///
+pub fn bench_json(year: u16, day: u8, checksum: &str, benchmark: &Benchmark) -> JsonObject {
+    let stats = |s: Stats| {
+        JsonObject::new()
+            .number("min_ns", s.min.as_nanos())
+            .number("median_ns", s.median.as_nanos())
+            .number("p95_ns", s.p95.as_nanos())
+            .to_string()
+    };
+
+    JsonObject::new()
+        .number("year", year)
+        .number("day", day)
+        .string("input_checksum", checksum)
+        .number("iterations", benchmark.config.iterations as u128)
+        .number("warmup", benchmark.config.warmup as u128)
+        .raw("parse", stats(benchmark.parse))
+        .optional("part1", benchmark.part1, stats)
+        .optional("part2", benchmark.part2, stats)
+}
+
+/// FORMAT: {"year":2023,"day":9,"error":".."}
+///
+pub fn error_json(year: u16, day: u8, error: &str) -> JsonObject {
+    JsonObject::new()
+        .number("year", year)
+        .number("day", day)
+        .string("error", error)
+}
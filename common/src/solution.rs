@@ -0,0 +1,256 @@
+use crate::bench::{self, BenchConfig, Benchmark, Timings};
+use crate::error::ParseError;
+use crate::input::{self, InputSource};
+use crate::report::{self, Format};
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Instant;
+
+/// The common interface for a single day's puzzle
+///
+/// The raw input is parsed once and then shared between both parts. A part that can't give an answer for
+/// the input (no path, a total too large to hold...) fails with a message rather than returning it as the answer
+///
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(data: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<impl Display, String>;
+    fn part2(input: &Self::Input) -> Result<impl Display, String>;
+}
+
+/// Which part of the puzzle to run
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+/// The answers from a run. A part is None if it wasn't asked for and an error if it ran but failed
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<Result<String, String>>,
+    pub part2: Option<Result<String, String>>,
+}
+
+/// The answer, or why there isn't one, for printing
+///
+pub fn answer_text(answer: &Result<String, String>) -> String {
+    match answer {
+        Ok(a) => a.clone(),
+        Err(e) => format!("failed ({})", e),
+    }
+}
+
+type SolveFn = fn(&str, Option<Part>) -> Result<(Answers, Timings), ParseError>;
+type BenchFn = fn(&str, Option<Part>, BenchConfig) -> Result<Benchmark, ParseError>;
+
+/// Type erased handle to a day so that the runner can hold all the days in one table
+///
+#[derive(Copy, Clone)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    /// Directory (relative to the repo root) containing the day's input.txt and test_input.txt
+    pub dir: &'static str,
+    solve: SolveFn,
+    bench: BenchFn,
+}
+
+impl Solver {
+    pub fn of<S: Solution>(dir: &'static str) -> Solver {
+        Solver {
+            year: S::YEAR,
+            day: S::DAY,
+            dir,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    /// Parse the data and run the requested part, or both parts if None, timing each phase
+    ///
+    pub fn solve(&self, data: &str, part: Option<Part>) -> Result<(Answers, Timings), ParseError> {
+        (self.solve)(data, part)
+    }
+
+    /// As solve but each phase is run many times to get stable timings
+    ///
+    pub fn bench(&self, data: &str, part: Option<Part>, config: BenchConfig) -> Result<Benchmark, ParseError> {
+        (self.bench)(data, part, config)
+    }
+}
+
+fn solve<S: Solution>(data: &str, part: Option<Part>) -> Result<(Answers, Timings), ParseError> {
+    let mut answers = Answers::default();
+    let mut timings = Timings::default();
+
+    let now = Instant::now();
+    let input = S::parse(data)?;
+    timings.parse = now.elapsed();
+
+    //Converting to a string is part of the timing in case the Display is doing any of the work
+    if part != Some(Part::Two) {
+        let now = Instant::now();
+        answers.part1 = Some(S::part1(&input).map(|a| a.to_string()));
+        timings.part1 = Some(now.elapsed());
+    }
+    if part != Some(Part::One) {
+        let now = Instant::now();
+        answers.part2 = Some(S::part2(&input).map(|a| a.to_string()));
+        timings.part2 = Some(now.elapsed());
+    }
+    Ok((answers, timings))
+}
+
+/// The standalone binary for each day - reads input.txt from the working directory (or the given
+/// path, stdin or test_input.txt) and prints both parts with the time taken by each phase
+///
+/// Usage: day_N [<input path> | -] [--example] [--bench] [--format <text|json>]
+///
+pub fn day_main<S: Solution>() {
+    let args = parse_day_args(std::env::args().skip(1)).unwrap_or_else(|e| exit_with_error(e));
+    let data = args.source.read(Path::new(".")).unwrap_or_else(|e| exit_with_error(e));
+    let solver = Solver::of::<S>(".");
+
+    if args.bench {
+        let config = BenchConfig::default();
+        let benchmark = solver.bench(&data, None, config).unwrap_or_else(|e| exit_with_error(e));
+        if args.format == Format::Json {
+            println!("{}", report::bench_json(S::YEAR, S::DAY, &input::checksum(&data), &benchmark));
+            return;
+        }
+        println!("{} iterations after {} warm-up", config.iterations, config.warmup);
+        print!("{}", benchmark);
+        return;
+    }
+
+    let (answers, timings) = solver.solve(&data, None).unwrap_or_else(|e| exit_with_error(e));
+    if args.format == Format::Json {
+        println!("{}", report::run_json(S::YEAR, S::DAY, &input::checksum(&data), &answers, &timings));
+        return;
+    }
+    println!(
+        "Part 1: {}, Part 2: {}, took {:#?} (parse {:#?}, part 1 {:#?}, part 2 {:#?})",
+        answers.part1.as_ref().map(answer_text).unwrap_or_default(),
+        answers.part2.as_ref().map(answer_text).unwrap_or_default(),
+        timings.total(),
+        timings.parse,
+        timings.part1.unwrap_or_default(),
+        timings.part2.unwrap_or_default()
+    );
+}
+
+struct DayArgs {
+    source: InputSource,
+    bench: bool,
+    format: Format,
+}
+
+fn parse_day_args(mut args: impl Iterator<Item = String>) -> Result<DayArgs, String> {
+    let mut day_args = DayArgs {
+        source: InputSource::Default,
+        bench: false,
+        format: Format::Text,
+    };
+
+    while let Some(a) = args.next() {
+        let next = match a.as_str() {
+            "--example" => InputSource::Example,
+            "--bench" => {
+                day_args.bench = true;
+                continue;
+            }
+            "--format" => {
+                let f = args.next().and_then(|f| Format::from_arg(&f));
+                day_args.format = f.ok_or("--format must be text or json")?;
+                continue;
+            }
+            _ if a.starts_with("--") => {
+                return Err(format!(
+                    "Unknown option '{}'\nUsage: [<input path> | -] [--example] [--bench] [--format <text|json>]",
+                    a
+                ))
+            }
+            _ => InputSource::from_arg(&a),
+        };
+
+        if day_args.source != InputSource::Default {
+            return Err("Only one input can be given".to_string());
+        }
+        day_args.source = next;
+    }
+
+    Ok(day_args)
+}
+
+fn exit_with_error(e: impl Display) -> ! {
+    eprintln!("{}", e);
+    std::process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Part 2 only has an answer for even numbers
+    ///
+    struct Halve;
+
+    impl Solution for Halve {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input = u32;
+
+        fn parse(data: &str) -> Result<Self::Input, ParseError> {
+            data.trim().parse().map_err(|_| ParseError::new(1, 1, data, "Expected a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, String> {
+            Ok(input * 2)
+        }
+
+        fn part2(input: &Self::Input) -> Result<impl Display, String> {
+            match input % 2 {
+                0 => Ok(input / 2),
+                _ => Err(format!("{} is odd", input)),
+            }
+        }
+    }
+
+    #[test]
+    fn failed_parts_are_not_answers() {
+        let solver = Solver::of::<Halve>(".");
+
+        let (answers, _) = solver.solve("8", None).unwrap();
+        assert_eq!(answers.part1, Some(Ok("16".to_string())));
+        assert_eq!(answers.part2, Some(Ok("4".to_string())));
+
+        let (answers, timings) = solver.solve("7", None).unwrap();
+        assert_eq!(answers.part1, Some(Ok("14".to_string())));
+        assert_eq!(answers.part2, Some(Err("7 is odd".to_string())));
+        assert_eq!(answer_text(answers.part2.as_ref().unwrap()), "failed (7 is odd)");
+
+        let json = report::run_json(2000, 1, "abc", &answers, &timings).to_string();
+        assert!(json.contains(r#""part1":"14","part2":null,"part1_error":null,"part2_error":"7 is odd""#), "{}", json);
+
+        let (answers, _) = solver.solve("7", Some(Part::One)).unwrap();
+        assert_eq!(answers.part2, None);
+    }
+}